@@ -49,6 +49,20 @@ impl TestSinkContract {
         // call the SinkContract constructor
         let constructor_args: Vec<Val> = (admin_addr, carbon_addr, csink_addr).into_val(&env);
         let _: () = env.invoke_contract(&sink_addr, &Symbol::new(&env, "__constructor"), constructor_args);
+
+        // list the project that is used by the tests
+        let sink_client = sink_contract::Client::new(&env, &sink_addr);
+        let project_info = sink_contract::ProjectInfo {
+            registry: String::from_str(&env, "Verra"),
+            methodology: String::from_str(&env, "VM0007"),
+            vintage: 2023,
+            country: String::from_str(&env, "KE"),
+            metadata_uri: String::from_str(&env, ""),
+        };
+        let project_id = Symbol::new(&env, "VCS1360");
+        sink_client.add_project(&project_id, &project_info);
+        // back any amount that the tests can sink with inventory
+        sink_client.add_project_inventory(&project_id, &i64::MAX);
     }
   
     pub fn test_active(env: Env) -> bool {
@@ -67,7 +81,6 @@ impl TestSinkContract {
         funder: Address, 
         recipient: Address, 
         amount: i64, 
-        project_id: Symbol,
     ) -> bool {
         // bail if `amount` is not valid for mint
         // TODO: check on `assume` status in komet#74
@@ -101,6 +114,7 @@ impl TestSinkContract {
         let recipient_csink_before = csink_token_client.balance(&recipient);
 
         // Call the `sink_carbon` method of the sink contract
+        let project_id = Symbol::new(&env, "VCS1360");
         let memo_text = String::from_str(&env, "");
         let sink_res = sink_client.try_sink_carbon(
             &funder, &recipient, &amount, &project_id, &memo_text
@@ -1,13 +1,155 @@
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error,
-    token::{StellarAssetClient, TokenClient}, 
-    Address, Env, String, Symbol
+    contract, contractimpl, contracttype, panic_with_error,
+    token::StellarAssetClient, 
+    Address, BytesN, Env, String, Symbol, Vec
 };
 
-use crate::errors::{publish_invoke_error, publish_sac_error, SACError, SinkError};
-use crate::storage_types::{extend_instance_ttl, set_is_active, DataKey};
-use crate::utils::quantize_to_kg;
+use crate::errors::SinkError;
+use crate::events::{
+    publish_active_changed, publish_admin_changed, publish_admin_proposal_changed, publish_admin_reset,
+    publish_allowlist_only_changed, publish_fee_changed, publish_forward_event, publish_forwarding_changed,
+    publish_inventory_changed, publish_list_changed, publish_maximum_changed, publish_migrate, publish_minimum_changed, publish_project_changed,
+    publish_quantization_changed, publish_rate_limit_changed, publish_role_changed, publish_scheduled_op,
+    publish_sink_event, publish_successor_changed, publish_timelock_delay_changed, publish_treasury_changed,
+    publish_ttl_policy_changed, publish_upgrade, ForwardEventData, SinkEventData, SINK_EVENT_VERSION,
+};
+use crate::fees::{compute_fee, read_fee_bps, read_treasury, write_fee_bps, write_treasury, MAX_FEE_BPS};
+use crate::limits::{
+    check_maximum, check_rate_limit, read_rate_limit, read_sink_maximum, read_usage, record_usage, write_rate_limit,
+    write_sink_maximum, RateLimit,
+};
+use crate::migrations::{migrate_storage, read_schema_version, write_schema_version, SCHEMA_VERSION};
+use crate::projects::{
+    check_inventory, check_project_listed, consume_inventory, read_inventory, read_project,
+    read_projects, register_project, write_inventory, write_project,
+};
+use crate::retirements::{
+    list_by_funder, list_by_recipient, read_retirement, read_total, read_totals, record_retirement,
+};
+use crate::roles::{read_role, require_role, write_role, Role};
+use crate::sac::{
+    burn_carbon, burn_carbon_from, check_burn, check_mint, extend_sac_ttl, mint_carbonsink, transfer_fee,
+};
+use crate::screening::{
+    check_funder, check_recipient, read_allowlist_only, read_list, read_listed, write_allowlist_only, write_listed,
+    ScreeningList,
+};
+use crate::storage_types::{
+    extend_instance_ttl, read_operator, read_quantization_unit, read_rounding_policy, read_ttl_policy, set_is_active,
+    write_operator, write_quantization, write_ttl_policy, DataKey, Project, ProjectInfo, QuantizationUnit, Retirement,
+    RoundingPolicy, SinkTotals, TtlPolicy,
+};
+use crate::successor::{
+    inactive_error, read_forwarding, read_successor, resolve_active_successor, write_forwarding, MAX_FORWARD_HOPS,
+    MAX_RESOLVE_HOPS,
+};
+use crate::timelock::{
+    read_scheduled_op, read_timelock_delay, remove_scheduled_op, require_no_timelock, require_op_role, schedule,
+    write_timelock_delay, AdminOp, ScheduledOp,
+};
+use crate::ttl::extend_storage_ttl;
+use crate::utils::quantize;
+
+/// The maximum number of items in a single `sink_carbon_batch` call.
+/// Every item writes six ledger entries of its own (the retirement record, the index and total entries,
+/// and the recipient's CarbonSINK balance) on top of the entries that every sink writes, and a transaction
+/// can write at most 25 entries, so larger batches would exceed the write limit of the network.
+/// Payouts to hundreds of recipients must be split over many transactions.
+pub(crate) const MAX_BATCH_SIZE: u32 = 2;
+
+/// A single sink operation within a `sink_carbon_batch` call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BatchSinkItem {
+    pub recipient: Address,
+    pub amount: i64,
+    pub project_id: Symbol,
+    pub memo_text: String,
+}
+
+/// The predicted outcome of a `sink_carbon` call, as returned by `check_sink`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SinkCheck {
+    /// The amount that would be burned and minted, quantized to the quantization unit.
+    pub amount: i64,
+    /// The protocol fee that would be charged on top of `amount`.
+    pub fee: i64,
+    /// The part of the requested amount that would not be sunk because of the quantization.
+    /// Negative if the amount would be rounded up.
+    pub remainder: i64,
+    /// The codes of every `SinkError` that would make the sink fail; empty if the sink is expected to succeed.
+    pub errors: Vec<u32>,
+}
+
+/// The latest active contract in the successor chain, as returned by `get_latest_contract`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LatestContract {
+    pub address: Address,
+    /// The number of successors that were followed to find `address`; 0 if this contract is active.
+    pub hops: u32,
+}
 
+/// All instance settings of this contract, as returned by `get_config`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SinkConfig {
+    /// The version of the contract crate that was built into the deployed WASM.
+    pub contract_version: String,
+    pub schema_version: u32,
+    pub admin: Address,
+    pub pending_admin: Option<Address>,
+    pub carbon_id: Address,
+    pub carbonsink_id: Address,
+    pub is_active: bool,
+    pub sink_minimum: i64,
+    pub sink_maximum: Option<i64>,
+    /// The window of the rate limit in ledgers; 0 if no rate limit is set.
+    pub rate_limit_window_ledgers: u32,
+    /// The cap of the rate limit; 0 if no rate limit is set.
+    pub rate_limit_max_amount: i128,
+    pub allowlist_only: bool,
+    /// The address of this contract if no successor is set.
+    pub successor: Address,
+    pub forwarding: bool,
+    pub fee_bps: u32,
+    pub treasury: Address,
+    pub quantization_unit: QuantizationUnit,
+    pub rounding_policy: RoundingPolicy,
+    pub timelock_delay: u32,
+    pub ttl_policy: TtlPolicy,
+}
+
+/// The outcome of a successful sink, as returned by `sink_carbon`, `sink_carbon_from` and `sink_carbon_batch`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SinkReceipt {
+    /// The ID of the new retirement record; 0 in the Mercury build, which does not write storage.
+    pub retirement_id: u64,
+    /// The amount that was requested by the caller.
+    pub requested_amount: i64,
+    /// The amount that was burned and minted, quantized to the quantization unit.
+    pub amount: i64,
+    /// The part of the requested amount that was not sunk because of the quantization, and stayed with the funder.
+    /// Negative if the amount was rounded up.
+    pub remainder: i64,
+    /// The protocol fee that was charged on top of `amount`.
+    pub fee: i64,
+    pub project_id: Symbol,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+/// The outcome of a single item of a `sink_carbon_batch` call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum BatchSinkResult {
+    /// The item was sunk; holds the receipt of the sink.
+    Sunk(SinkReceipt),
+    /// The item was skipped because of the `SinkError` with this code.
+    Failed(u32),
+}
 
 #[contract]
 pub struct SinkContract;
@@ -29,31 +171,45 @@ impl SinkContract {
         env.storage().instance().set(&DataKey::CarbonSinkID, &carbonsink_id);
         env.storage().instance().set(&DataKey::IsActive, &true);
         env.storage().instance().set(&DataKey::SinkMinimum, &1_000_000_i64);  // 100 kg
+        write_schema_version(&env, SCHEMA_VERSION);
     }
 
     /// Sinks CARBON tokens by burning them from the `funder` and minting equivalent CarbonSINK tokens to the `recipient`.
-    /// Quantizes the amount to kg resolution, checks minimum requirements, and handles authorization for CarbonSINK minting.
-    /// Emits an event if the Mercury feature is enabled, otherwise performs the full sink operation.
+    /// Quantizes the amount to the quantization unit (kg by default), checks minimum requirements, and handles authorization for CarbonSINK minting.
+    /// Emits an event if the Mercury feature is enabled, otherwise performs the full sink operation,
+    /// stores a persistent `Retirement` record under a new sequential ID, and publishes a `("sink_carbon", "sink")` event.
     /// 
     /// ## Arguments
     /// 
     /// * `funder` - The address funding the sink operation (spends CARBON).
     /// * `recipient` - The address receiving the CarbonSINK tokens.
     /// * `amount` - The amount of CARBON to sink in decigrams.
-    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// * `project_id` - The impact project ID (e.g. VCS1360), which must be listed in the project registry.
     /// * `memo_text` - A retirement reason or transaction reference.
     /// 
     /// ## Returns
     /// 
-    /// A Result indicating success (unit type) or a `SinkError`.
+    /// A Result containing the `SinkReceipt` with the new retirement ID and the quantized amount, or a `SinkError`.
+    /// The Mercury build does not write storage and always returns a retirement ID of 0.
     /// 
     /// ## Errors
     /// 
     /// * `AmountTooLow` - The quantized amount is below the minimum sink amount.
-    /// * `InsufficientBalance` - The funder's CARBON balance is insufficient.
-    /// * `AccountOrTrustlineMissing` - The funder or recipient has a missing trustline or account.
+    /// * `AmountTooHigh` - The quantized amount exceeds the maximum sink amount.
+    /// * `RateLimited` - The funder would exceed the amount that it can sink within the rate limit window.
+    /// * `AddressBlocked` - The funder or recipient is on the denylist, or not on the allowlist in allowlist-only mode.
+    /// * `InvalidRecipient` - The recipient is this contract or the CARBON or CarbonSINK SAC.
+    /// * `AmountNotAligned` - The amount is not a multiple of the quantization unit, and the rounding policy is strict.
+    /// * `InsufficientBalance` - The funder's CARBON balance is insufficient for the amount and the protocol fee.
+    /// * `AccountOrTrustlineMissing` - The funder, recipient or treasury has a missing trustline or account.
     /// * `TrustlineLimitReached` - The recipient's trustline limit is reached for CarbonSINK.
     /// * `ContractDeactivated` - The contract is not currently active, preventing this operation.
+    /// * `ContractSuperseded` - The contract is deactivated and has a successor, and forwarding is disabled.
+    /// * `SuccessorCycle`, `SuccessorChainTooLong`, `SuccessorDeadEnd` - Forwarding is enabled, but no active
+    ///   successor could be found.
+    /// * `UnknownProject` - The `project_id` is not present in the project registry.
+    /// * `ProjectDelisted` - The `project_id` has been delisted from the project registry.
+    /// * `ProjectInventoryExhausted` - The project's available inventory is lower than the quantized amount.
     /// 
     /// ## Notes    
     ///  
@@ -70,12 +226,24 @@ impl SinkContract {
     /// Internally, crossed flows ensure atomicity: funder → CARBON removed from circulation (one axis),
     /// CarbonSINK minter → recipient (other axis).
     /// 
-    /// Prerequisites: The contract must be active, the funder must have a sufficient CARBON balance and authorize the burn,
+    /// If a protocol fee is set, the funder additionally transfers `amount * fee_bps / 10_000` CARBON
+    /// to the treasury before the burn. The fee is charged on top of the quantized amount, so the minimum
+    /// sink amount and the project inventory apply to the sunk amount only, and the quantization remainder
+    /// stays with the funder.
+    /// 
+    /// If the admin has enabled forwarding with `set_forwarding`, a superseded contract forwards the sink
+    /// to the first active contract in its successor chain, following at most 5 successors. The returned receipt
+    /// then refers to a retirement record of that contract, and the funder must also authorize its `sink_carbon` call.
+    /// 
+    /// Every sink also extends the TTL of the CARBON and CarbonSINK SAC instances, see `extend_ttl`.
+    /// 
+    /// Prerequisites: The contract must be active, the project must be listed with sufficient inventory, the funder must have a sufficient CARBON balance and authorize the burn,
     /// the recipient must have a CarbonSINK trustline with a limit at least as large as the quantized amount,
-    /// and the amount must be at least the minimum sink amount after quantization to kg resolution.
+    /// and the amount must be at least the minimum sink amount after quantization.
     /// 
     /// Common pitfalls: Ensure the funder has enough CARBON and the recipient has a valid trustline; otherwise,
     /// the operation will fail with errors like `InsufficientBalance` or `TrustlineLimitReached`.
+    #[cfg_attr(feature = "mercury", allow(unreachable_code))]
     pub fn sink_carbon(
         env: Env, 
         funder: Address, 
@@ -83,9 +251,79 @@ impl SinkContract {
         amount: i64, 
         project_id: Symbol,
         memo_text: String,
-    ) -> Result<(), SinkError> {
+    ) -> Result<SinkReceipt, SinkError> {
+        #[cfg(feature = "mercury")]
+        {
+            let receipt = Self::mercury_receipt(&env, amount, &project_id)?;
+            // emit sink event
+            crate::retroshades::SinkEvent {
+                funder,
+                recipient,
+                amount,
+                project_id,
+                memo_text,
+                ledger: env.ledger().sequence(),
+                timestamp: env.ledger().timestamp(),
+            }
+            .emit(&env);
+            // and return early to spare the ZVM
+            return Ok(receipt);
+        }
+
+        // a superseded contract forwards the sink to its successor, if enabled
+        if !Self::is_active(env.clone()) && read_forwarding(&env) && read_successor(&env).is_some() {
+            return Self::forward_sink(&env, funder, recipient, amount, project_id, memo_text);
+        }
+        Self::sink(&env, funder, recipient, amount, project_id, memo_text, false)
+    }
+
+    /// Sinks CARBON on behalf of the `funder`, by burning from the CARBON allowance that the `funder`
+    /// has granted to this contract and minting equivalent CarbonSINK tokens to the `recipient`.
+    /// This allows relayers and custodians to trigger sinks without a signature from the `funder`
+    /// for every sink. Otherwise, it behaves exactly like `sink_carbon`.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `operator` - The address triggering the sink operation, which must be approved by the `funder`.
+    /// * `funder` - The address funding the sink operation (spends CARBON).
+    /// * `recipient` - The address receiving the CarbonSINK tokens.
+    /// * `amount` - The amount of CARBON to sink in decigrams.
+    /// * `project_id` - The impact project ID (e.g. VCS1360), which must be listed in the project registry.
+    /// * `memo_text` - A retirement reason or transaction reference.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the `SinkReceipt` with the new retirement ID and the quantized amount, or a `SinkError`.
+    /// The Mercury build does not write storage and always returns a retirement ID of 0.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperatorNotApproved` - The `funder` has not approved the `operator` with `set_operator`.
+    /// * `InsufficientAllowance` - The `funder`'s CARBON allowance for this contract is insufficient.
+    /// * Any error of `sink_carbon`. Sinks from an allowance are never forwarded, so a superseded
+    ///   contract fails with `ContractSuperseded`.
+    /// 
+    /// ## Notes
+    /// 
+    /// Prerequisites: The `funder` must approve the `operator` with `set_operator`, and must grant
+    /// this contract a CARBON allowance with the `approve` function of the CARBON SAC, which also
+    /// covers the protocol fee.
+    #[cfg_attr(feature = "mercury", allow(unreachable_code))]
+    pub fn sink_carbon_from(
+        env: Env,
+        operator: Address,
+        funder: Address,
+        recipient: Address,
+        amount: i64,
+        project_id: Symbol,
+        memo_text: String,
+    ) -> Result<SinkReceipt, SinkError> {
+        // the Mercury build does not use `operator`
+        #[allow(unused, clippy::redundant_locals)]
+        let operator = operator;
         #[cfg(feature = "mercury")]
         {
+            let receipt = Self::mercury_receipt(&env, amount, &project_id)?;
             // emit sink event
             crate::retroshades::SinkEvent {
                 funder,
@@ -98,86 +336,250 @@ impl SinkContract {
             }
             .emit(&env);
             // and return early to spare the ZVM
-            return Ok(());
+            return Ok(receipt);
         }
-        #[allow(unused)]
-        let (project_id, memo_text) = (project_id, memo_text);
+        operator.require_auth();
+        if !read_operator(&env, &funder, &operator) {
+            return Err(SinkError::OperatorNotApproved);
+        }
+        Self::sink(&env, funder, recipient, amount, project_id, memo_text, true)
+    }
 
+    /// Approves or revokes an `operator` that may call `sink_carbon_from` on behalf of the `funder`.
+    /// Requires authorization by the `funder` and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `funder` - The address funding the sink operations (spends CARBON).
+    /// * `operator` - The address that may trigger sink operations for the `funder`.
+    /// * `approved` - Whether to approve (true) or revoke (false) the `operator`.
+    pub fn set_operator(env: Env, funder: Address, operator: Address, approved: bool) {
+        extend_instance_ttl(&env);
+        funder.require_auth();
+        write_operator(&env, &funder, &operator, approved);
+    }
+
+    /// Checks if the `operator` may call `sink_carbon_from` on behalf of the `funder`.
+    /// Extends the instance TTL before returning the value.
+    pub fn is_operator(env: Env, funder: Address, operator: Address) -> bool {
+        extend_instance_ttl(&env);
+        read_operator(&env, &funder, &operator)
+    }
+
+    /// Sinks CARBON for several recipients at once, with a single authorization and burn by the `funder`.
+    /// Each item is validated and minted like a separate `sink_carbon` call, after which the sum of
+    /// the quantized amounts of all sunk items is burned from the `funder` in one go.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `funder` - The address funding the sink operations (spends CARBON).
+    /// * `items` - The recipients, amounts, project IDs and memos to sink for (at most 2 items).
+    /// * `atomic` - If true, the whole batch fails on the first failing item. If false, failing items
+    ///   are skipped and reported in the results, and only the remaining items are sunk.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the outcome of every item in the order of `items`, or a `SinkError`.
+    /// The Mercury build does not write storage and reports a retirement ID of 0 for every item.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `ContractDeactivated` - The contract is not currently active, preventing this operation.
+    /// * `ContractSuperseded` - The contract is deactivated and has a successor. Batches are never forwarded.
+    /// * `EmptyBatch` - The batch does not contain any items.
+    /// * `BatchTooLarge` - The batch contains more than 2 items.
+    /// * `AddressBlocked` - The funder is on the denylist, or not on the allowlist in allowlist-only mode.
+    /// * `InsufficientBalance` - The funder's CARBON balance is insufficient for the total amount and fee.
+    /// * `AccountOrTrustlineMissing` - The funder has a missing trustline or account.
+    /// * Any error of `sink_carbon` for a failing item, if `atomic` is true.
+    /// 
+    /// ## Notes
+    /// 
+    /// The maximum sink amount applies to each item, and the rate limit of the funder to the sum of the sunk items.
+    /// Every recipient needs ledger entries of its own, so a payout to many recipients must be split
+    /// into batches of at most 2 items, each submitted as a separate transaction.
+    #[cfg_attr(feature = "mercury", allow(unreachable_code))]
+    pub fn sink_carbon_batch(
+        env: Env,
+        funder: Address,
+        items: Vec<BatchSinkItem>,
+        atomic: bool,
+    ) -> Result<Vec<BatchSinkResult>, SinkError> {
+        // the Mercury build does not use `atomic`
+        #[allow(unused, clippy::redundant_locals)]
+        let atomic = atomic;
+        #[cfg(feature = "mercury")]
+        {
+            // emit a sink event per item
+            let mut results = Vec::new(&env);
+            for item in items.iter() {
+                let receipt = match Self::mercury_receipt(&env, item.amount, &item.project_id) {
+                    Ok(receipt) => receipt,
+                    Err(err) => {
+                        results.push_back(BatchSinkResult::Failed(err as u32));
+                        continue;
+                    }
+                };
+                crate::retroshades::SinkEvent {
+                    funder: funder.clone(),
+                    recipient: item.recipient,
+                    amount: item.amount,
+                    project_id: item.project_id,
+                    memo_text: item.memo_text,
+                    ledger: env.ledger().sequence(),
+                    timestamp: env.ledger().timestamp(),
+                }
+                .emit(&env);
+                results.push_back(BatchSinkResult::Sunk(receipt));
+            }
+            // and return early to spare the ZVM
+            return Ok(results);
+        }
         extend_instance_ttl(&env);
+        extend_sac_ttl(&env);
         if !Self::is_active(env.clone()) {
-            panic_with_error!(&env, SinkError::ContractDeactivated);
+            return Err(inactive_error(&env));
         }
+        if items.is_empty() {
+            return Err(SinkError::EmptyBatch);
+        }
+        if items.len() > MAX_BATCH_SIZE {
+            return Err(SinkError::BatchTooLarge);
+        }
+        check_funder(&env, &funder)?;
+        funder.require_auth();
 
-        // quantize `amount` to kg resolution
-        let amount = quantize_to_kg(amount);
+        // mint CarbonSINK for each item first, so that only sunk items count towards the burn
+        let mut results = Vec::new(&env);
+        let mut total_amount: i128 = 0;
+        let mut total_fee: i128 = 0;
+        for item in items.iter() {
+            let outcome = check_recipient(&env, &item.recipient)
+                .and_then(|_| Self::prepare_sink(&env, &item.project_id, item.amount))
+                .and_then(|amount| check_rate_limit(&env, &funder, amount).map(|_| amount))
+                .and_then(|amount| mint_carbonsink(&env, &item.recipient, amount.into()).map(|_| amount));
+            match outcome {
+                Ok(amount) => {
+                    let fee = compute_fee(&env, amount);
+                    total_amount += amount as i128;
+                    total_fee += fee as i128;
+                    let receipt = Self::complete_sink(
+                        &env, funder.clone(), item.recipient, item.amount, amount, fee, item.project_id, item.memo_text
+                    )?;
+                    results.push_back(BatchSinkResult::Sunk(receipt));
+                }
+                Err(err) if !atomic => results.push_back(BatchSinkResult::Failed(err as u32)),
+                Err(err) => return Err(err),
+            }
+        }
 
-        // check if `amount` equals or exceeds minimum
-        let minimum_sink_amount: i64 = env.storage().instance().get(&DataKey::SinkMinimum).unwrap();
-        if amount < minimum_sink_amount {
-            return Err(SinkError::AmountTooLow);
+        // `funder` pays the total protocol fee and burns the total amount of CARBON; a failure reverts all mints
+        if total_fee > 0 {
+            transfer_fee(&env, &funder, total_fee, false)?;
+        }
+        if total_amount > 0 {
+            burn_carbon(&env, &funder, total_amount)?;
         }
 
-        // `funder` burns `amount` of CARBON
-        funder.require_auth();
-        let carbon_id = env.storage().instance().get(&DataKey::CarbonID).unwrap();
-        let carbon_client = TokenClient::new(&env, &carbon_id);
-        match carbon_client.try_burn(&funder, &amount.into()) {
-            Ok(_) => {}
-            Err(Ok(err)) => {
-                let error_code = err.get_code();
-                publish_sac_error(&env, "carbon_sac_error", error_code);
-                if error_code == SACError::BalanceError as u32 {
-                    // most likely the funder's CARBON balance is too low
-                    return Err(SinkError::InsufficientBalance);
-                } else if error_code == SACError::TrustlineMissingError as u32 {
-                    // burn internals check the trustline; account is only checked for native transfer
-                    return Err(SinkError::AccountOrTrustlineMissing);
-                } // re-panic for unexpected errors
-                panic_with_error!(&env, err);
-            },
-            Err(Err(invoke_err)) => {
-                publish_invoke_error(&env, "burn_carbon", invoke_err);
-                panic!("InvokeError: {:?}", invoke_err);
-            }
+        Ok(results)
+    }
+
+    /// Predicts the outcome of a `sink_carbon` call with the same arguments, without executing it.
+    /// Runs the checks of `sink_carbon` without requiring authorization, and collects every error
+    /// that would make the sink fail instead of returning on the first one.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `funder` - The address that would fund the sink operation (spends CARBON).
+    /// * `recipient` - The address that would receive the CarbonSINK tokens.
+    /// * `amount` - The amount of CARBON to sink in decigrams.
+    /// * `project_id` - The impact project ID (e.g. VCS1360) to sink against.
+    /// 
+    /// ## Returns
+    /// 
+    /// A `SinkCheck` with the quantized amount, the protocol fee, the quantization remainder and the blocking errors.
+    /// 
+    /// ## Notes
+    /// 
+    /// The SAC does not expose trustline limits, so `TrustlineLimitReached` is only predicted when
+    /// the recipient's CarbonSINK balance would exceed the maximum balance of any trustline.
+    /// A successful check does not guarantee a successful sink if the state changes in the meantime.
+    /// A superseded contract reports `ContractSuperseded` even if it forwards sinks, so check the sink
+    /// with the successor instead.
+    pub fn check_sink(env: Env, funder: Address, recipient: Address, amount: i64, project_id: Symbol) -> SinkCheck {
+        extend_instance_ttl(&env);
+        let mut errors = Vec::new(&env);
+        if !Self::is_active(env.clone()) {
+            errors.push_back(inactive_error(&env) as u32);
+        }
+        if let Err(err) = check_funder(&env, &funder).and_then(|_| check_recipient(&env, &recipient)) {
+            errors.push_back(err as u32);
         }
 
-        // `recipient` receives `amount` of CarbonSINK
-        let carbonsink_id = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
-        let carbonsink_client = StellarAssetClient::new(&env, &carbonsink_id);
-        match carbonsink_client.try_set_authorized(&recipient, &true) {
-            Ok(_) => {}
-            Err(Ok(err)) => {
-                let error_code = err.get_code();
-                publish_sac_error(&env, "csink_sac_error", error_code);
-                if error_code == SACError::TrustlineMissingError as u32 {
-                    // `set_authorization` reads the trustline entry, not the account entry
-                    return Err(SinkError::AccountOrTrustlineMissing);
-                } // re-panic for unexpected errors
-                panic_with_error!(&env, err);
-            },
-            Err(Err(invoke_err)) => {
-                publish_invoke_error(&env, "set_authorized", invoke_err);
-                panic!("InvokeError: {:?}", invoke_err);
+        let quantized_amount = match Self::quantize_amount(&env, amount) {
+            Ok(quantized_amount) => quantized_amount,
+            Err(err) => {
+                // continue the checks with the truncated amount
+                errors.push_back(err as u32);
+                quantize(amount, read_quantization_unit(&env), RoundingPolicy::Truncate).unwrap()
             }
         };
-        match carbonsink_client.try_mint(&recipient, &amount.into()) {
-            Ok(_) => {}
-            Err(Ok(err)) => {
-                let error_code = err.get_code();
-                publish_sac_error(&env, "csink_sac_error", error_code);
-                if error_code == SACError::BalanceError as u32 {
-                    return Err(SinkError::TrustlineLimitReached);
-                } // re-panic for unexpected errors
-                panic_with_error!(&env, err);
-            },
-            Err(Err(invoke_err)) => {
-                publish_invoke_error(&env, "mint_csink", invoke_err);
-                panic!("InvokeError: {:?}", invoke_err);
-            }
+        if let Err(err) = Self::check_minimum(&env, quantized_amount) {
+            errors.push_back(err as u32);
+        }
+        if let Err(err) = check_maximum(&env, quantized_amount) {
+            errors.push_back(err as u32);
+        }
+        if let Err(err) = check_rate_limit(&env, &funder, quantized_amount) {
+            errors.push_back(err as u32);
+        }
+        if let Err(err) = check_project_listed(&env, &project_id)
+            .and_then(|_| check_inventory(&env, &project_id, quantized_amount))
+        {
+            errors.push_back(err as u32);
+        }
+        let fee = compute_fee(&env, quantized_amount);
+        if let Err(err) = check_burn(&env, &funder, quantized_amount as i128 + fee as i128) {
+            errors.push_back(err as u32);
+        }
+        if let Err(err) = check_mint(&env, &recipient, quantized_amount.into()) {
+            errors.push_back(err as u32);
         }
-        carbonsink_client.set_authorized(&recipient, &false);
 
-        Ok(())
+        SinkCheck {
+            amount: quantized_amount,
+            fee,
+            remainder: amount - quantized_amount,
+            errors,
+        }
+    }
+
+    /// Extends the TTL of the contract instance and code, and of the CARBON and CarbonSINK SAC instances,
+    /// so that neither this contract nor the asset contracts are archived during a quiet period.
+    /// Also extends a page of the persistent entries that can go unused for a long time: the project registry,
+    /// the role members, the operator approvals, the screening lists, the scheduled operations and
+    /// the retirement records with the cumulative totals of their recipients and funders.
+    /// Anyone can call this function, e.g. a keeper that runs daily and pages through all entries.
+    /// Entries are only extended once their TTL has dropped below the threshold of the TTL policy.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `cursor` - The number of the first item to extend; 0 for the first page.
+    /// * `limit` - The maximum number of items to extend (at most 20).
+    /// 
+    /// ## Returns
+    /// 
+    /// The cursor of the next page, or `None` if this was the last page.
+    /// 
+    /// ## Notes
+    /// 
+    /// The per-address retirement indexes are not part of the pages. They are extended whenever they are
+    /// written or listed with the `list_retirements_*` functions, so an index that has not been used for
+    /// longer than the persistent TTL is archived and must be restored before it can be listed again.
+    pub fn extend_ttl(env: Env, cursor: u32, limit: u32) -> Option<u32> {
+        extend_instance_ttl(&env);
+        extend_sac_ttl(&env);
+        extend_storage_ttl(&env, cursor, limit)
     }
 
     /// Retrieves the minimum sink amount required for a sink operation.
@@ -191,115 +593,1358 @@ impl SinkContract {
         env.storage().instance().get(&DataKey::SinkMinimum).unwrap()
     }
 
-    /// Checks if the contract is currently active.
-    /// A deactivated contract that does not have a successor may be temporarily paused.
+    /// Retrieves the maximum amount of a single sink operation.
     /// Extends the instance TTL before returning the value.
     /// 
     /// ## Returns
     /// 
-    /// A boolean indicating if the contract is active.
-    pub fn is_active(env: Env) -> bool {
+    /// The maximum sink amount as an i64, or `None` if no maximum is set.
+    pub fn get_maximum_sink_amount(env: Env) -> Option<i64> {
         extend_instance_ttl(&env);
-        env.storage().instance().get(&DataKey::IsActive).unwrap()
+        read_sink_maximum(&env)
     }
 
-    /// Retrieves the contract successor address, or the current contract address if no successor is set.
-    /// Note that the successor contract may itself already be superseded by another contract.
-    /// To find the latest active contract, call this function on each successor until it returns its own address.
+    /// Retrieves the cap on the amount that a single funder can sink within a window of ledgers.
     /// Extends the instance TTL before returning the value.
     /// 
     /// ## Returns
     /// 
-    /// The successor address or the current contract address.
-    pub fn get_contract_successor(env: Env) -> Address {
+    /// The `RateLimit`, or `None` if no rate limit is set.
+    pub fn get_rate_limit(env: Env) -> Option<RateLimit> {
         extend_instance_ttl(&env);
-
-        // provide current contract address if this func is called when contract is still active
-        env.storage().instance().get(&DataKey::ContractSuccessor).unwrap_or(
-            env.current_contract_address()
-        )
+        read_rate_limit(&env)
     }
 
-    // ADMIN FUNCTIONS
-
-    /// Sets the contract successor address for upgrades.
-    /// Requires admin authorization and extends the instance TTL.
+    /// Retrieves the amount that `funder` has sunk in the rate limit window that ends with the current ledger.
+    /// Extends the instance TTL before returning the value.
     /// 
     /// ## Arguments
     /// 
-    /// * `successor` - The address of the successor contract.
-    pub fn set_contract_successor(env: Env, successor: Address) {
+    /// * `funder` - The address of the funder.
+    /// 
+    /// ## Returns
+    /// 
+    /// The sunk amount as an i128; zero if no rate limit is set.
+    /// 
+    /// ## Notes
+    /// 
+    /// The usage is recorded in 24 buckets per window, so an amount counts for up to 1/24 of the window
+    /// longer than the window itself.
+    pub fn get_funder_usage(env: Env, funder: Address) -> i128 {
         extend_instance_ttl(&env);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        match read_rate_limit(&env) {
+            Some(limit) => read_usage(&env, &funder, &limit),
+            None => 0,
+        }
+    }
 
-        env.storage().instance().set(&DataKey::ContractSuccessor, &successor);
+    /// Retrieves the protocol fee in basis points, which is charged on top of every sunk amount.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The protocol fee in basis points as a u32.
+    pub fn get_fee_bps(env: Env) -> u32 {
+        extend_instance_ttl(&env);
+        read_fee_bps(&env)
     }
 
-    /// Sets the minimum sink amount for sink operations.
-    /// Requires admin authorization, validates the amount is non-negative, and extends the instance TTL.
+    /// Retrieves the address that receives the protocol fees, or the admin address if no treasury is set.
+    /// Extends the instance TTL before returning the value.
     /// 
-    /// ## Arguments
+    /// ## Returns
     /// 
-    /// * `amount` - The new minimum sink amount (must be >= 0).
+    /// The treasury address.
+    pub fn get_treasury(env: Env) -> Address {
+        extend_instance_ttl(&env);
+        read_treasury(&env)
+    }
+
+    /// Retrieves the unit to which sunk amounts are quantized.
+    /// Extends the instance TTL before returning the value.
     /// 
     /// ## Returns
     /// 
-    /// A Result indicating success or an error if the amount is negative.
+    /// The `QuantizationUnit`, which is `Kilogram` unless the admin has changed it.
+    pub fn get_quantization_unit(env: Env) -> QuantizationUnit {
+        extend_instance_ttl(&env);
+        read_quantization_unit(&env)
+    }
+
+    /// Retrieves the policy for amounts that are not a whole multiple of the quantization unit.
+    /// Extends the instance TTL before returning the value.
     /// 
-    /// ## Errors
+    /// ## Returns
     /// 
-    /// * `NegativeAmount` - The provided amount must be positive.
-    pub fn set_minimum_sink_amount(env: Env, amount: i64) -> Result<(), SinkError> {
+    /// The `RoundingPolicy`, which is `Truncate` unless the admin has changed it.
+    pub fn get_rounding_policy(env: Env) -> RoundingPolicy {
         extend_instance_ttl(&env);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        if amount < 0 {
-            panic_with_error!(&env, SinkError::NegativeAmount);
-        } else {
-            env.storage().instance().set(&DataKey::SinkMinimum, &amount);
-            Ok(())
-        }
+        read_rounding_policy(&env)
     }
 
-    /// Resets the CarbonSINK SAC admin by setting it to the current contract admin and deactivates the contract.
-    /// This is called as part of the upgrade process, before setting a new SinkContract as the CarbonSINK SAC admin.
-    /// Requires admin authorization and extends the instance TTL.
+    /// Checks if the contract is currently active.
+    /// A deactivated contract that does not have a successor may be temporarily paused.
+    /// Extends the instance TTL before returning the value.
     /// 
     /// ## Returns
     /// 
-    /// The address of the admin.
-    pub fn reset_admin(env: Env) -> Address {
+    /// A boolean indicating if the contract is active.
+    pub fn is_active(env: Env) -> bool {
         extend_instance_ttl(&env);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        env.storage().instance().get(&DataKey::IsActive).unwrap()
+    }
 
-        // set contract admin as the CarbonSINK SAC admin
-        let carbonsink_id = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
-        let carbonsink_client = StellarAssetClient::new(&env, &carbonsink_id);
-        carbonsink_client.set_admin(&admin);
+    /// Retrieves the version of the storage layout of this contract.
+    /// Contracts that were deployed before schema versioning (0.4.x) have version 1 until they are migrated.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The schema version as a u32.
+    pub fn get_schema_version(env: Env) -> u32 {
+        extend_instance_ttl(&env);
+        read_schema_version(&env)
+    }
 
-        // deactivate this contract
-        set_is_active(&env, false);
+    /// Retrieves all instance settings of this contract in one call, so that monitoring can verify
+    /// that a deployment matches its expected configuration. Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// A `SinkConfig` with the contract and schema versions, the admin, asset IDs and every setting.
+    /// 
+    /// ## Notes
+    /// 
+    /// Role holders are kept in persistent storage per address and cannot be listed; check them with `has_role`.
+    pub fn get_config(env: Env) -> SinkConfig {
+        extend_instance_ttl(&env);
+        let instance = env.storage().instance();
+        let rate_limit = read_rate_limit(&env);
+        SinkConfig {
+            contract_version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            schema_version: read_schema_version(&env),
+            admin: instance.get(&DataKey::Admin).unwrap(),
+            pending_admin: instance.get(&DataKey::PendingAdmin),
+            carbon_id: instance.get(&DataKey::CarbonID).unwrap(),
+            carbonsink_id: instance.get(&DataKey::CarbonSinkID).unwrap(),
+            is_active: instance.get(&DataKey::IsActive).unwrap(),
+            sink_minimum: instance.get(&DataKey::SinkMinimum).unwrap(),
+            sink_maximum: read_sink_maximum(&env),
+            rate_limit_window_ledgers: rate_limit.as_ref().map_or(0, |limit| limit.window_ledgers),
+            rate_limit_max_amount: rate_limit.as_ref().map_or(0, |limit| limit.max_amount),
+            allowlist_only: read_allowlist_only(&env),
+            successor: read_successor(&env).unwrap_or(env.current_contract_address()),
+            forwarding: read_forwarding(&env),
+            fee_bps: read_fee_bps(&env),
+            treasury: read_treasury(&env),
+            quantization_unit: read_quantization_unit(&env),
+            rounding_policy: read_rounding_policy(&env),
+            timelock_delay: read_timelock_delay(&env),
+            ttl_policy: read_ttl_policy(&env),
+        }
+    }
 
-        admin
+    /// Retrieves the address of the contract admin.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The admin address.
+    pub fn get_admin(env: Env) -> Address {
+        extend_instance_ttl(&env);
+        env.storage().instance().get(&DataKey::Admin).unwrap()
     }
 
-    /// Activates the contract, allowing sink operations.
-    /// Requires admin authorization and extends the instance TTL.
-    pub fn activate(env: Env) {
+    /// Retrieves the address that has been proposed as the new admin with `propose_admin`, if any.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The pending admin address, or None if there is no pending proposal.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
         extend_instance_ttl(&env);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        set_is_active(&env, true);
+        env.storage().instance().get(&DataKey::PendingAdmin)
     }
 
-    /// Deactivates the contract, preventing sink operations.
-    /// Requires admin authorization and extends the instance TTL.
-    pub fn deactivate(env: Env) {
+    /// Checks if `account` has been granted `role` by the admin.
+    /// The admin itself is allowed to call every role-gated function, without being granted the role.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `role` - The role to check.
+    /// * `account` - The address to check.
+    /// 
+    /// ## Returns
+    /// 
+    /// A boolean indicating if `account` holds `role`.
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
         extend_instance_ttl(&env);
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        set_is_active(&env, false);
+        read_role(&env, role, &account)
+    }
+
+    /// Retrieves the timelock delay in ledgers between scheduling and executing a sensitive admin operation.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The timelock delay as a u32; zero if sensitive admin operations take effect immediately.
+    pub fn get_timelock_delay(env: Env) -> u32 {
+        extend_instance_ttl(&env);
+        read_timelock_delay(&env)
+    }
+
+    /// Retrieves the TTL thresholds and extension amounts in ledgers that apply to the storage of this contract.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The `TtlPolicy`; by default, the instance is extended to 30 days and persistent entries to 180 days.
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        extend_instance_ttl(&env);
+        read_ttl_policy(&env)
+    }
+
+    /// Retrieves an operation that has been scheduled with `schedule_op` and not yet executed or cancelled.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `op_id` - The ID of the scheduled operation.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperationNotFound` - No scheduled operation exists for this ID.
+    pub fn get_scheduled_op(env: Env, op_id: u64) -> Result<ScheduledOp, SinkError> {
+        extend_instance_ttl(&env);
+        read_scheduled_op(&env, op_id).ok_or(SinkError::OperationNotFound)
+    }
+
+    /// Resolves the latest active SinkContract by following the successor chain of this contract
+    /// with cross-contract calls, so that clients can find the current contract in one simulation.
+    /// The `max_hops` are capped at 10 successors. Extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `max_hops` - The maximum number of successors to follow.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the address of the first active contract in the chain and the number of hops to it,
+    /// or a `SinkError`. This contract is returned with 0 hops while it is active.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `ContractDeactivated` - The chain ends at a deactivated contract without a successor.
+    /// * `SuccessorCycle` - The chain leads back to a contract that has already been visited.
+    /// * `SuccessorChainTooLong` - No active contract was found within `max_hops` successors.
+    /// * `SuccessorDeadEnd` - A successor in the chain is not a SinkContract.
+    pub fn get_latest_contract(env: Env, max_hops: u32) -> Result<LatestContract, SinkError> {
+        extend_instance_ttl(&env);
+        if Self::is_active(env.clone()) {
+            return Ok(LatestContract { address: env.current_contract_address(), hops: 0 });
+        }
+        let (address, hops) = resolve_active_successor(&env, max_hops.min(MAX_RESOLVE_HOPS))?;
+        Ok(LatestContract { address, hops })
+    }
+
+    /// Checks if this contract forwards `sink_carbon` calls to its successor while it is superseded.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// A boolean indicating if forwarding is enabled.
+    pub fn is_forwarding(env: Env) -> bool {
+        extend_instance_ttl(&env);
+        read_forwarding(&env)
+    }
+
+    /// Retrieves the contract successor address, or the current contract address if no successor is set.
+    /// Note that the successor contract may itself already be superseded by another contract.
+    /// To find the latest active contract, call `get_latest_contract`.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Returns
+    /// 
+    /// The successor address or the current contract address.
+    pub fn get_contract_successor(env: Env) -> Address {
+        extend_instance_ttl(&env);
+
+        // provide current contract address if this func is called when contract is still active
+        env.storage().instance().get(&DataKey::ContractSuccessor).unwrap_or(
+            env.current_contract_address()
+        )
+    }
+
+    /// Retrieves the retirement record with the given ID.
+    /// Extends the instance TTL and the TTL of the record before returning it.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `id` - The retirement ID, as returned by `sink_carbon`.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `RetirementNotFound` - No retirement record exists for this ID.
+    pub fn get_retirement(env: Env, id: u64) -> Result<Retirement, SinkError> {
+        extend_instance_ttl(&env);
+        read_retirement(&env, id).ok_or(SinkError::RetirementNotFound)
+    }
+
+    /// Lists the retirements received by `recipient`, oldest first.
+    /// The `limit` is capped at 50 records per page.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `recipient` - The address that received the CarbonSINK tokens.
+    /// * `cursor` - The position in the recipient's listing to start from (0 for the first page).
+    /// * `limit` - The maximum number of records to return.
+    pub fn list_retirements_by_recipient(env: Env, recipient: Address, cursor: u32, limit: u32) -> Vec<Retirement> {
+        extend_instance_ttl(&env);
+        list_by_recipient(&env, &recipient, cursor, limit)
+    }
+
+    /// Lists the retirements funded by `funder`, oldest first.
+    /// The `limit` is capped at 50 records per page.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `funder` - The address that spent the CARBON tokens.
+    /// * `cursor` - The position in the funder's listing to start from (0 for the first page).
+    /// * `limit` - The maximum number of records to return.
+    pub fn list_retirements_by_funder(env: Env, funder: Address, cursor: u32, limit: u32) -> Vec<Retirement> {
+        extend_instance_ttl(&env);
+        list_by_funder(&env, &funder, cursor, limit)
+    }
+
+    /// Retrieves the cumulative figures for all sink operations performed by this contract:
+    /// the total amount of CarbonSINK minted, the number of sinks, and the number of unique recipients.
+    /// Extends the instance TTL before returning the value.
+    pub fn get_totals(env: Env) -> SinkTotals {
+        extend_instance_ttl(&env);
+        read_totals(&env)
+    }
+
+    /// Retrieves the total amount of CarbonSINK minted to `recipient` by this contract.
+    /// Extends the instance TTL before returning the value.
+    pub fn get_recipient_total(env: Env, recipient: Address) -> i128 {
+        extend_instance_ttl(&env);
+        read_total(&env, &DataKey::RecipientTotal(recipient))
+    }
+
+    /// Retrieves the total amount of CARBON sunk by `funder` through this contract.
+    /// Extends the instance TTL before returning the value.
+    pub fn get_funder_total(env: Env, funder: Address) -> i128 {
+        extend_instance_ttl(&env);
+        read_total(&env, &DataKey::FunderTotal(funder))
+    }
+
+    /// Retrieves the total amount of CARBON sunk for `project_id` through this contract.
+    /// Extends the instance TTL before returning the value.
+    pub fn get_project_total(env: Env, project_id: Symbol) -> i128 {
+        extend_instance_ttl(&env);
+        read_total(&env, &DataKey::ProjectTotal(project_id))
+    }
+
+    /// Retrieves a project from the project registry, including delisted projects.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// 
+    /// ## Errors
+    /// 
+    /// * `UnknownProject` - The project ID is not present in the project registry.
+    pub fn get_project(env: Env, project_id: Symbol) -> Result<Project, SinkError> {
+        extend_instance_ttl(&env);
+        read_project(&env, &project_id).ok_or(SinkError::UnknownProject)
+    }
+
+    /// Lists the projects in the project registry in order of registration, including delisted projects.
+    /// The `limit` is capped at 50 projects per page.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `cursor` - The position in the registry to start from (0 for the first page).
+    /// * `limit` - The maximum number of projects to return.
+    pub fn list_projects(env: Env, cursor: u32, limit: u32) -> Vec<Project> {
+        extend_instance_ttl(&env);
+        read_projects(&env, cursor, limit)
+    }
+
+    /// Checks if `account` is on the denylist or the allowlist.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `list` - The list to check: `Denylist` or `Allowlist`.
+    /// * `account` - The address to check.
+    pub fn is_listed(env: Env, list: ScreeningList, account: Address) -> bool {
+        extend_instance_ttl(&env);
+        read_listed(&env, list, &account)
+    }
+
+    /// Lists the addresses on the denylist or the allowlist. The `limit` is capped at 50 addresses per page.
+    /// Removing an address moves the last address of the list into its position, so the order is not stable.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `list` - The list to read: `Denylist` or `Allowlist`.
+    /// * `cursor` - The position in the list to start from (0 for the first page).
+    /// * `limit` - The maximum number of addresses to return.
+    pub fn list_addresses(env: Env, list: ScreeningList, cursor: u32, limit: u32) -> Vec<Address> {
+        extend_instance_ttl(&env);
+        read_list(&env, list, cursor, limit)
+    }
+
+    /// Checks if allowlist-only mode is enabled, in which only addresses on the allowlist can fund or receive a sink.
+    /// Extends the instance TTL before returning the value.
+    pub fn is_allowlist_only(env: Env) -> bool {
+        extend_instance_ttl(&env);
+        read_allowlist_only(&env)
+    }
+
+    /// Retrieves the available inventory of a project, i.e. the amount of CARBON that can still be sunk for it.
+    /// Extends the instance TTL before returning the value.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    pub fn get_project_inventory(env: Env, project_id: Symbol) -> i128 {
+        extend_instance_ttl(&env);
+        read_inventory(&env, &project_id)
+    }
+
+    // ADMIN FUNCTIONS
+
+    /// Sets the contract successor address for upgrades.
+    /// Requires authorization of the admin or an `UpgradeManager`, publishes an admin event,
+    /// and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `UpgradeManager` role.
+    /// * `successor` - The address of the successor contract.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `Unauthorized` - The caller is neither the admin nor an `UpgradeManager`.
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn set_contract_successor(env: Env, caller: Address, successor: Address) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::UpgradeManager)?;
+        require_no_timelock(&env)?;
+        Self::apply_contract_successor(&env, &caller, &successor);
+        Ok(())
+    }
+
+    /// Sets the minimum sink amount for sink operations.
+    /// Requires authorization of the admin or a `ConfigManager`, validates the amount is non-negative,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `ConfigManager` role.
+    /// * `amount` - The new minimum sink amount (must be >= 0).
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result indicating success or an error if the amount is negative.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `NegativeAmount` - The provided amount must be positive.
+    /// * `Unauthorized` - The caller is neither the admin nor a `ConfigManager`.
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn set_minimum_sink_amount(env: Env, caller: Address, amount: i64) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::ConfigManager)?;
+        if amount < 0 {
+            panic_with_error!(&env, SinkError::NegativeAmount);
+        }
+        require_no_timelock(&env)?;
+        Self::apply_minimum_sink_amount(&env, &caller, amount);
+        Ok(())
+    }
+
+    /// Sets the maximum amount of a single sink operation, so that a mistaken amount cannot burn
+    /// the whole balance of a funder. The maximum applies to the quantized amount, excluding the fee.
+    /// Requires authorization of the admin or a `ConfigManager`, publishes an admin event, and extends the instance TTL.
+    /// The maximum is not timelocked, so that it can be lowered right away.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `ConfigManager` role.
+    /// * `amount` - The new maximum sink amount (must be >= 0), or `None` to remove the maximum.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `NegativeAmount` - The provided amount is negative.
+    /// * `Unauthorized` - The caller is neither the admin nor a `ConfigManager`.
+    pub fn set_maximum_sink_amount(env: Env, caller: Address, amount: Option<i64>) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::ConfigManager)?;
+        if amount.is_some_and(|amount| amount < 0) {
+            return Err(SinkError::NegativeAmount);
+        }
+        let old_maximum = read_sink_maximum(&env);
+        write_sink_maximum(&env, amount);
+        publish_maximum_changed(&env, &caller, old_maximum, amount);
+        Ok(())
+    }
+
+    /// Sets the cap on the amount that a single funder can sink within a window of ledgers, so that
+    /// a compromised funder key or a faulty integration cannot drain a balance in one go.
+    /// A window starts with the first sink of a funder after its previous window has ended, and the
+    /// cap applies to the quantized amounts, excluding fees. Sinks from an allowance count towards the funder.
+    /// Requires authorization of the admin or a `ConfigManager`, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `ConfigManager` role.
+    /// * `limit` - The new rate limit, with a window of at most 518400 ledgers (about 30 days),
+    ///   or `None` to remove the rate limit.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `InvalidRateLimit` - The window is zero or too long, or the cap is negative.
+    /// * `Unauthorized` - The caller is neither the admin nor a `ConfigManager`.
+    /// 
+    /// ## Notes
+    /// 
+    /// The window slides: a sink is rejected if the funder would exceed the cap within the window that ends
+    /// with the current ledger. Changing the window keeps the usage that the funders have recorded.
+    pub fn set_rate_limit(env: Env, caller: Address, limit: Option<RateLimit>) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::ConfigManager)?;
+        if let Some(limit) = &limit {
+            limit.validate()?;
+        }
+        let old_limit = read_rate_limit(&env);
+        write_rate_limit(&env, &limit);
+        publish_rate_limit_changed(&env, &caller, &old_limit, &limit);
+        Ok(())
+    }
+
+    /// Sets the protocol fee that is charged in CARBON on top of every sunk amount, and transferred to the treasury.
+    /// Requires authorization of the admin or a `ConfigManager`, validates the fee against the maximum fee,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `ConfigManager` role.
+    /// * `fee_bps` - The new protocol fee in basis points (must be <= 1000, i.e. 10%).
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result indicating success or an error if the fee is too high.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `FeeTooHigh` - The provided fee exceeds the maximum fee.
+    /// * `Unauthorized` - The caller is neither the admin nor a `ConfigManager`.
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn set_fee_bps(env: Env, caller: Address, fee_bps: u32) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::ConfigManager)?;
+        if fee_bps > MAX_FEE_BPS {
+            return Err(SinkError::FeeTooHigh);
+        }
+        require_no_timelock(&env)?;
+        Self::apply_fee_bps(&env, &caller, fee_bps);
+        Ok(())
+    }
+
+    /// Sets the address that receives the protocol fees.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `treasury` - The new treasury address, which must be able to hold CARBON.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn set_treasury(env: Env, treasury: Address) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        require_no_timelock(&env)?;
+        Self::apply_treasury(&env, &admin, &treasury);
+        Ok(())
+    }
+
+    /// Sets the unit to which sunk amounts are quantized, and the policy for amounts that are not
+    /// a whole multiple of that unit. Requires authorization of the admin or a `ConfigManager`,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `ConfigManager` role.
+    /// * `unit` - The new quantization unit: `Gram`, `Kilogram` or `Tonne`.
+    /// * `rounding` - The new rounding policy: `Truncate` leaves the remainder with the funder,
+    ///   `Strict` rejects non-aligned amounts with `AmountNotAligned`, and `Nearest` rounds halves up,
+    ///   so that the funder may burn slightly more than the requested amount.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `Unauthorized` - The caller is neither the admin nor a `ConfigManager`.
+    pub fn set_quantization(
+        env: Env, caller: Address, unit: QuantizationUnit, rounding: RoundingPolicy
+    ) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::ConfigManager)?;
+        let old_unit = read_quantization_unit(&env);
+        let old_rounding = read_rounding_policy(&env);
+        write_quantization(&env, unit, rounding);
+        publish_quantization_changed(&env, &caller, old_unit, unit, old_rounding, rounding);
+        Ok(())
+    }
+
+    /// Resets the CarbonSINK SAC admin by setting it to the current contract admin and deactivates the contract.
+    /// This is called as part of the upgrade process, before setting a new SinkContract as the CarbonSINK SAC admin.
+    /// Requires authorization of the admin or an `UpgradeManager`, publishes an admin event, and extends the instance TTL.
+    /// The CarbonSINK SAC admin is always handed to the contract admin, not to an `UpgradeManager`.
+    /// 
+    /// After an admin transfer, the contract admin is no longer the CarbonSINK issuer, so the CarbonSINK SAC admin
+    /// is handed to the new admin. A pending admin proposal is cancelled, so that the SAC admin role cannot
+    /// pass to an address that has not yet accepted the contract admin role.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `UpgradeManager` role.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the address of the admin, or a `SinkError`.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `Unauthorized` - The caller is neither the admin nor an `UpgradeManager`.
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn reset_admin(env: Env, caller: Address) -> Result<Address, SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::UpgradeManager)?;
+        require_no_timelock(&env)?;
+        Ok(Self::apply_reset_admin(&env))
+    }
+
+    /// Sets the delay in ledgers between scheduling a sensitive admin operation with `schedule_op` and
+    /// executing it with `execute_op`. While the delay is zero (the default), `set_contract_successor`,
+    /// `reset_admin`, `set_minimum_sink_amount`, `set_fee_bps` and this function take effect immediately;
+    /// otherwise they fail with `OperationTimelocked` and must be scheduled.
+    /// `deactivate` is never timelocked, so that the contract can be paused in an emergency.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `delay` - The new timelock delay in ledgers (must be <= 518400, i.e. about 30 days).
+    /// 
+    /// ## Errors
+    /// 
+    /// * `TimelockDelayTooLong` - The provided delay exceeds the maximum delay.
+    /// * `OperationTimelocked` - The timelock delay is already set, so the change must be scheduled.
+    pub fn set_timelock_delay(env: Env, delay: u32) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        AdminOp::SetTimelockDelay(delay).validate()?;
+        require_no_timelock(&env)?;
+        Self::apply_timelock_delay(&env, &admin, delay);
+        Ok(())
+    }
+
+    /// Enables or disables the forwarding of `sink_carbon` calls to the successor contract while this
+    /// contract is deactivated and has a successor. Without forwarding, such calls fail with `ContractSuperseded`.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `enabled` - Whether to forward sinks (true) or reject them (false).
+    pub fn set_forwarding(env: Env, enabled: bool) {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_enabled = read_forwarding(&env);
+        write_forwarding(&env, enabled);
+        publish_forwarding_changed(&env, &admin, old_enabled, enabled);
+    }
+
+    /// Sets the TTL thresholds and extension amounts that apply to the storage of this contract.
+    /// The new policy applies from the next access of an entry, so the instance is extended with it right away.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `policy` - The new TTL policy, with thresholds at most their extension amounts, and extension amounts
+    ///   of at least one day (17280 ledgers) and at most the maximum TTL of the network.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `InvalidTtlPolicy` - The policy is outside the limits above.
+    pub fn set_ttl_policy(env: Env, policy: TtlPolicy) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        policy.validate(&env)?;
+        let old_policy = read_ttl_policy(&env);
+        write_ttl_policy(&env, &policy);
+        extend_instance_ttl(&env);
+        publish_ttl_policy_changed(&env, &admin, &old_policy, &policy);
+        Ok(())
+    }
+
+    /// Schedules a sensitive admin operation, which can be executed with `execute_op` once
+    /// the timelock delay has elapsed. The operation is validated when it is scheduled.
+    /// Requires authorization of the admin or of an address with the role that the operation requires,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the role of the operation
+    ///   (`UpgradeManager` for the successor and `reset_admin`, `ConfigManager` for the minimum and fee).
+    ///   Only the admin can schedule a change of the timelock delay, an upgrade or a change of the treasury.
+    /// * `op` - The operation and its arguments.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the ID of the scheduled operation, or a `SinkError`.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `Unauthorized` - The caller is neither the admin nor holds the role of the operation.
+    /// * `NegativeAmount`, `FeeTooHigh`, `TimelockDelayTooLong` - The arguments of the operation are invalid.
+    pub fn schedule_op(env: Env, caller: Address, op: AdminOp) -> Result<u64, SinkError> {
+        extend_instance_ttl(&env);
+        require_op_role(&env, &caller, &op)?;
+        op.validate()?;
+        let scheduled = schedule(&env, op);
+        publish_scheduled_op(&env, "schedule_op", &caller, &scheduled);
+        Ok(scheduled.id)
+    }
+
+    /// Executes an operation that has been scheduled with `schedule_op`, once its timelock delay has elapsed.
+    /// Requires authorization of the admin or of an address with the role that the operation requires,
+    /// publishes the admin event of the operation and of the execution, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the role of the operation.
+    /// * `op_id` - The ID of the scheduled operation.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperationNotFound` - No scheduled operation exists for this ID, e.g. because it has been executed or cancelled.
+    /// * `Unauthorized` - The caller is neither the admin nor holds the role of the operation.
+    /// * `OperationNotReady` - The timelock delay of the operation has not yet elapsed.
+    pub fn execute_op(env: Env, caller: Address, op_id: u64) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let scheduled = read_scheduled_op(&env, op_id).ok_or(SinkError::OperationNotFound)?;
+        require_op_role(&env, &caller, &scheduled.op)?;
+        if env.ledger().sequence() < scheduled.ready_ledger {
+            return Err(SinkError::OperationNotReady);
+        }
+
+        remove_scheduled_op(&env, op_id);
+        match scheduled.op.clone() {
+            AdminOp::SetContractSuccessor(successor) => Self::apply_contract_successor(&env, &caller, &successor),
+            AdminOp::ResetAdmin => {
+                Self::apply_reset_admin(&env);
+            }
+            AdminOp::SetMinimumSinkAmount(amount) => Self::apply_minimum_sink_amount(&env, &caller, amount),
+            AdminOp::SetFeeBps(fee_bps) => Self::apply_fee_bps(&env, &caller, fee_bps),
+            AdminOp::SetTimelockDelay(delay) => Self::apply_timelock_delay(&env, &caller, delay),
+            AdminOp::Upgrade(new_wasm_hash) => Self::apply_upgrade(&env, &caller, new_wasm_hash),
+            AdminOp::SetTreasury(treasury) => Self::apply_treasury(&env, &caller, &treasury),
+        }
+        publish_scheduled_op(&env, "execute_op", &caller, &scheduled);
+        Ok(())
+    }
+
+    /// Cancels an operation that has been scheduled with `schedule_op`.
+    /// Requires authorization of the admin or of an address with the role that the operation requires,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the role of the operation.
+    /// * `op_id` - The ID of the scheduled operation.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperationNotFound` - No scheduled operation exists for this ID, e.g. because it has been executed or cancelled.
+    /// * `Unauthorized` - The caller is neither the admin nor holds the role of the operation.
+    pub fn cancel_op(env: Env, caller: Address, op_id: u64) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let scheduled = read_scheduled_op(&env, op_id).ok_or(SinkError::OperationNotFound)?;
+        require_op_role(&env, &caller, &scheduled.op)?;
+        remove_scheduled_op(&env, op_id);
+        publish_scheduled_op(&env, "cancel_op", &caller, &scheduled);
+        Ok(())
+    }
+
+    /// Upgrades this contract in place to the WASM with `new_wasm_hash`, which must already be uploaded.
+    /// The contract address, storage and CarbonSINK SAC admin are kept, so integrators need not change anything.
+    /// The new code takes effect after this invocation; call `migrate` afterwards to update the storage layout.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `new_wasm_hash` - The hash of the uploaded WASM of the new contract version.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `OperationTimelocked` - The timelock delay is set, so the operation must be scheduled with `schedule_op`.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        require_no_timelock(&env)?;
+        Self::apply_upgrade(&env, &admin, new_wasm_hash);
+        Ok(())
+    }
+
+    /// Migrates the storage layout of this contract to the schema version of the current code,
+    /// after an `upgrade`. Migrations are idempotent: calling this on an up-to-date contract does nothing.
+    /// Requires admin authorization, publishes an admin event if the schema version changes,
+    /// and extends the instance TTL.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the new schema version, or a `SinkError`.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `UnsupportedSchemaVersion` - The storage was written by a newer version of the contract.
+    /// 
+    /// ## Notes
+    /// 
+    /// Contracts that are migrated from 0.4.x have an empty project registry, so the admin must list
+    /// projects with `add_project` and `add_project_inventory` before sinks can succeed again.
+    pub fn migrate(env: Env) -> Result<u32, SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_version = migrate_storage(&env)?;
+        if old_version != SCHEMA_VERSION {
+            publish_migrate(&env, &admin, old_version, SCHEMA_VERSION);
+        }
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Proposes `new_admin` as the new contract admin, replacing any earlier proposal.
+    /// The admin role only passes to `new_admin` once it calls `accept_admin`.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `new_admin` - The address of the proposed admin.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let old_pending_admin = env.storage().instance().get(&DataKey::PendingAdmin).unwrap_or(
+            env.current_contract_address()
+        );
+        env.storage().instance().set(&DataKey::PendingAdmin, &new_admin);
+        publish_admin_proposal_changed(&env, "propose_admin", &admin, &old_pending_admin, &new_admin);
+    }
+
+    /// Accepts the admin role that has been proposed with `propose_admin`.
+    /// Requires authorization of the pending admin, publishes an admin event, and extends the instance TTL.
+    /// The CarbonSINK SAC admin is not affected, since that remains this contract.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result containing the address of the new admin, or a `SinkError`.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `NoPendingAdmin` - No admin has been proposed.
+    pub fn accept_admin(env: Env) -> Result<Address, SinkError> {
+        extend_instance_ttl(&env);
+        let new_admin: Address = env.storage().instance().get(&DataKey::PendingAdmin).ok_or(
+            SinkError::NoPendingAdmin
+        )?;
+        new_admin.require_auth();
+
+        let old_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        publish_admin_changed(&env, &old_admin, &new_admin);
+        Ok(new_admin)
+    }
+
+    /// Cancels the pending admin proposal.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Returns
+    /// 
+    /// A Result indicating success or an error if there is no pending proposal.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `NoPendingAdmin` - No admin has been proposed.
+    pub fn cancel_admin_proposal(env: Env) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let pending_admin: Address = env.storage().instance().get(&DataKey::PendingAdmin).ok_or(
+            SinkError::NoPendingAdmin
+        )?;
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        publish_admin_proposal_changed(
+            &env, "cancel_admin_proposal", &admin, &pending_admin, &env.current_contract_address()
+        );
+        Ok(())
+    }
+
+    /// Activates the contract, allowing sink operations.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    pub fn activate(env: Env) {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_is_active = Self::is_active(env.clone());
+        set_is_active(&env, true);
+        publish_active_changed(&env, "activate", &admin, old_is_active, true);
+    }
+
+    /// Deactivates the contract, preventing sink operations.
+    /// Requires authorization of the admin or a `Pauser`, publishes an admin event, and extends the instance TTL.
+    /// Only the admin can activate the contract again.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `caller` - The admin or an address with the `Pauser` role.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `Unauthorized` - The caller is neither the admin nor a `Pauser`.
+    pub fn deactivate(env: Env, caller: Address) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        require_role(&env, &caller, Role::Pauser)?;
+        let old_is_active = Self::is_active(env.clone());
+        set_is_active(&env, false);
+        publish_active_changed(&env, "deactivate", &caller, old_is_active, false);
+        Ok(())
+    }
+
+    /// Grants `role` to `account`. Requires admin authorization, publishes an admin event,
+    /// and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `role` - The role to grant: `Pauser`, `ConfigManager` or `UpgradeManager`.
+    /// * `account` - The address that receives the role.
+    pub fn grant_role(env: Env, role: Role, account: Address) {
+        Self::set_role(&env, "grant_role", role, &account, true);
+    }
+
+    /// Revokes `role` from `account`. Requires admin authorization, publishes an admin event,
+    /// and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `role` - The role to revoke: `Pauser`, `ConfigManager` or `UpgradeManager`.
+    /// * `account` - The address that loses the role.
+    pub fn revoke_role(env: Env, role: Role, account: Address) {
+        Self::set_role(&env, "revoke_role", role, &account, false);
+    }
+
+    /// Adds `account` to the denylist or the allowlist. An address on the denylist can neither fund
+    /// nor receive a sink, even if it is also on the allowlist. Requires admin authorization,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `list` - The list to add to: `Denylist` or `Allowlist`.
+    /// * `account` - The address to add; adding an address that is already listed has no effect.
+    pub fn add_to_list(env: Env, list: ScreeningList, account: Address) {
+        Self::set_listed(&env, "add_to_list", list, &account, true);
+    }
+
+    /// Removes `account` from the denylist or the allowlist. Requires admin authorization,
+    /// publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `list` - The list to remove from: `Denylist` or `Allowlist`.
+    /// * `account` - The address to remove; removing an address that is not listed has no effect.
+    pub fn remove_from_list(env: Env, list: ScreeningList, account: Address) {
+        Self::set_listed(&env, "remove_from_list", list, &account, false);
+    }
+
+    /// Enables or disables allowlist-only mode, in which only addresses on the allowlist can fund or receive
+    /// a sink. Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `enabled` - Whether to require funders and recipients to be on the allowlist (true) or not (false).
+    pub fn set_allowlist_only(env: Env, enabled: bool) {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_enabled = read_allowlist_only(&env);
+        write_allowlist_only(&env, enabled);
+        publish_allowlist_only_changed(&env, &admin, old_enabled, enabled);
+    }
+
+    /// Adds a project to the project registry, or relists a delisted project with new metadata.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// * `info` - The project metadata.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `ProjectAlreadyListed` - The project is already listed in the registry.
+    pub fn add_project(env: Env, project_id: Symbol, info: ProjectInfo) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        register_project(&env, project_id.clone(), info)?;
+        publish_project_changed(&env, "add_project", &admin, &project_id, false, true);
+        Ok(())
+    }
+
+    /// Updates the metadata of a project in the project registry, without changing its listing status.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// * `info` - The new project metadata.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `UnknownProject` - The project ID is not present in the project registry.
+    pub fn update_project(env: Env, project_id: Symbol, info: ProjectInfo) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let mut project = read_project(&env, &project_id).ok_or(SinkError::UnknownProject)?;
+        project.info = info;
+        write_project(&env, &project);
+        publish_project_changed(&env, "update_project", &admin, &project_id, project.is_listed, project.is_listed);
+        Ok(())
+    }
+
+    /// Delists a project, preventing further sink operations for it.
+    /// Existing retirements that reference the project are not affected.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// 
+    /// ## Errors
+    /// 
+    /// * `UnknownProject` - The project ID is not present in the project registry.
+    pub fn delist_project(env: Env, project_id: Symbol) -> Result<(), SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let mut project = read_project(&env, &project_id).ok_or(SinkError::UnknownProject)?;
+        let old_is_listed = project.is_listed;
+        project.is_listed = false;
+        write_project(&env, &project);
+        publish_project_changed(&env, "delist_project", &admin, &project_id, old_is_listed, false);
+        Ok(())
+    }
+
+    /// Adds to the available inventory of a project, after a new batch of its credits has been retired off-chain.
+    /// Requires admin authorization, publishes an admin event, and extends the instance TTL.
+    /// 
+    /// ## Arguments
+    /// 
+    /// * `project_id` - The impact project ID (e.g. VCS1360).
+    /// * `amount` - The amount of CARBON backed by the new batch, in decigrams.
+    /// 
+    /// ## Returns
+    /// 
+    /// The new available inventory of the project.
+    /// 
+    /// ## Errors
+    /// 
+    /// * `NegativeAmount` - The provided amount must be positive.
+    /// * `UnknownProject` - The project ID is not present in the project registry.
+    pub fn add_project_inventory(env: Env, project_id: Symbol, amount: i64) -> Result<i128, SinkError> {
+        extend_instance_ttl(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if amount < 0 {
+            return Err(SinkError::NegativeAmount);
+        }
+        read_project(&env, &project_id).ok_or(SinkError::UnknownProject)?;
+
+        let old_inventory = read_inventory(&env, &project_id);
+        let inventory = old_inventory + amount as i128;
+        write_inventory(&env, &project_id, inventory);
+        publish_inventory_changed(&env, &admin, &project_id, old_inventory, inventory);
+        Ok(inventory)
+    }
+}
+
+impl SinkContract {
+    /// Performs a sink operation for `sink_carbon` and `sink_carbon_from`.
+    /// If `burn_from_allowance` is true, the CARBON is burned from the allowance of this contract
+    /// instead of with the authorization of the `funder`.
+    fn sink(
+        env: &Env,
+        funder: Address,
+        recipient: Address,
+        amount: i64,
+        project_id: Symbol,
+        memo_text: String,
+        burn_from_allowance: bool,
+    ) -> Result<SinkReceipt, SinkError> {
+        extend_instance_ttl(env);
+        extend_sac_ttl(env);
+        if !Self::is_active(env.clone()) {
+            panic_with_error!(env, inactive_error(env));
+        }
+
+        // screen the funder and recipient before anything is burned
+        check_funder(env, &funder)?;
+        check_recipient(env, &recipient)?;
+
+        // validate the project and quantize `amount` to the quantization unit
+        let requested_amount = amount;
+        let amount = Self::prepare_sink(env, &project_id, amount)?;
+        check_rate_limit(env, &funder, amount)?;
+
+        if !burn_from_allowance {
+            funder.require_auth();
+        }
+
+        // `funder` pays the protocol fee on top of `amount`, before the burn
+        let fee = compute_fee(env, amount);
+        if fee > 0 {
+            transfer_fee(env, &funder, fee.into(), burn_from_allowance)?;
+        }
+
+        // `funder` burns `amount` of CARBON
+        if burn_from_allowance {
+            burn_carbon_from(env, &funder, amount.into())?;
+        } else {
+            burn_carbon(env, &funder, amount.into())?;
+        }
+
+        // `recipient` receives `amount` of CarbonSINK
+        mint_carbonsink(env, &recipient, amount.into())?;
+
+        // keep a persistent record of this retirement
+        Self::complete_sink(env, funder, recipient, requested_amount, amount, fee, project_id, memo_text)
+    }
+
+    /// Forwards a `sink_carbon` call to the first active contract in the successor chain, and returns
+    /// the receipt of that contract.
+    fn forward_sink(
+        env: &Env,
+        funder: Address,
+        recipient: Address,
+        amount: i64,
+        project_id: Symbol,
+        memo_text: String,
+    ) -> Result<SinkReceipt, SinkError> {
+        extend_instance_ttl(env);
+        let (successor, _) = resolve_active_successor(env, MAX_FORWARD_HOPS)?;
+        funder.require_auth();
+
+        // errors of the successor abort this invocation with the same error code
+        let successor_client = SinkContractClient::new(env, &successor);
+        let receipt = successor_client.sink_carbon(&funder, &recipient, &amount, &project_id, &memo_text);
+        publish_forward_event(env, ForwardEventData {
+            successor,
+            retirement_id: receipt.retirement_id,
+            funder,
+            recipient,
+        });
+        Ok(receipt)
+    }
+
+    /// Validates a sink for `project_id` and returns `amount` quantized to the quantization unit.
+    /// Does not modify any storage.
+    fn prepare_sink(env: &Env, project_id: &Symbol, amount: i64) -> Result<i64, SinkError> {
+        // only listed projects can be sunk against
+        check_project_listed(env, project_id)?;
+
+        // quantize `amount` to the quantization unit
+        let amount = Self::quantize_amount(env, amount)?;
+
+        // check if `amount` equals or exceeds minimum, and does not exceed the maximum
+        Self::check_minimum(env, amount)?;
+        check_maximum(env, amount)?;
+
+        // the project must have enough retired credits to back `amount`
+        check_inventory(env, project_id, amount)?;
+        Ok(amount)
+    }
+
+    /// Quantizes `amount` according to the quantization unit and rounding policy of this contract.
+    fn quantize_amount(env: &Env, amount: i64) -> Result<i64, SinkError> {
+        quantize(amount, read_quantization_unit(env), read_rounding_policy(env))
+    }
+
+    /// Sets the contract successor on behalf of `caller`.
+    fn apply_contract_successor(env: &Env, caller: &Address, successor: &Address) {
+        let old_successor = env.storage().instance().get(&DataKey::ContractSuccessor).unwrap_or(
+            env.current_contract_address()
+        );
+        env.storage().instance().set(&DataKey::ContractSuccessor, successor);
+        publish_successor_changed(env, caller, &old_successor, successor);
+    }
+
+    /// Sets the minimum sink amount on behalf of `caller`. The amount must have been validated.
+    fn apply_minimum_sink_amount(env: &Env, caller: &Address, amount: i64) {
+        let old_minimum: i64 = env.storage().instance().get(&DataKey::SinkMinimum).unwrap();
+        env.storage().instance().set(&DataKey::SinkMinimum, &amount);
+        publish_minimum_changed(env, caller, old_minimum, amount);
+    }
+
+    /// Sets the protocol fee on behalf of `caller`. The fee must have been validated.
+    fn apply_fee_bps(env: &Env, caller: &Address, fee_bps: u32) {
+        let old_fee_bps = read_fee_bps(env);
+        write_fee_bps(env, fee_bps);
+        publish_fee_changed(env, caller, old_fee_bps, fee_bps);
+    }
+
+    /// Sets the timelock delay on behalf of `caller`. The delay must have been validated.
+    fn apply_timelock_delay(env: &Env, caller: &Address, delay: u32) {
+        let old_delay = read_timelock_delay(env);
+        write_timelock_delay(env, delay);
+        publish_timelock_delay_changed(env, caller, old_delay, delay);
+    }
+
+    /// Sets the treasury on behalf of `caller`.
+    fn apply_treasury(env: &Env, caller: &Address, treasury: &Address) {
+        let old_treasury = read_treasury(env);
+        write_treasury(env, treasury);
+        publish_treasury_changed(env, caller, &old_treasury, treasury);
+    }
+
+    /// Replaces the code of this contract on behalf of `caller`. The new code takes effect after this invocation.
+    fn apply_upgrade(env: &Env, caller: &Address, new_wasm_hash: BytesN<32>) {
+        publish_upgrade(env, caller, &new_wasm_hash, read_schema_version(env));
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Hands the CarbonSINK SAC admin to the contract admin and deactivates this contract.
+    /// Returns the address of the admin.
+    fn apply_reset_admin(env: &Env) -> Address {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+
+        // cancel a pending admin proposal
+        if let Some(pending_admin) = env.storage().instance().get::<_, Address>(&DataKey::PendingAdmin) {
+            env.storage().instance().remove(&DataKey::PendingAdmin);
+            publish_admin_proposal_changed(
+                env, "cancel_admin_proposal", &admin, &pending_admin, &env.current_contract_address()
+            );
+        }
+
+        // set contract admin as the CarbonSINK SAC admin
+        let carbonsink_id = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
+        let carbonsink_client = StellarAssetClient::new(env, &carbonsink_id);
+        carbonsink_client.set_admin(&admin);
+
+        // deactivate this contract
+        let old_is_active = Self::is_active(env.clone());
+        set_is_active(env, false);
+        publish_admin_reset(env, &admin, &env.current_contract_address(), old_is_active, false);
+
+        admin
+    }
+
+    /// Grants or revokes `role` for `account` on behalf of the admin.
+    fn set_role(env: &Env, fn_name: &str, role: Role, account: &Address, granted: bool) {
+        extend_instance_ttl(env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_has_role = read_role(env, role, account);
+        write_role(env, role, account, granted);
+        publish_role_changed(env, fn_name, &admin, role, account, old_has_role, granted);
+    }
+
+    /// Adds `account` to `list` or removes it on behalf of the admin.
+    fn set_listed(env: &Env, fn_name: &str, list: ScreeningList, account: &Address, listed: bool) {
+        extend_instance_ttl(env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let old_listed = read_listed(env, list, account);
+        write_listed(env, list, account, listed);
+        publish_list_changed(env, fn_name, &admin, list, account, old_listed, listed);
+    }
+
+    /// Checks that the quantized `amount` equals or exceeds the minimum sink amount.
+    fn check_minimum(env: &Env, amount: i64) -> Result<(), SinkError> {
+        let minimum_sink_amount: i64 = env.storage().instance().get(&DataKey::SinkMinimum).unwrap();
+        if amount < minimum_sink_amount {
+            return Err(SinkError::AmountTooLow);
+        }
+        Ok(())
+    }
+
+    /// Updates the project inventory and the rate limit usage of the funder, stores the retirement record
+    /// and publishes the sink event for a sink that has been burned and minted. Returns the receipt of the sink.
+    #[allow(clippy::too_many_arguments)]
+    fn complete_sink(
+        env: &Env,
+        funder: Address,
+        recipient: Address,
+        requested_amount: i64,
+        amount: i64,
+        fee: i64,
+        project_id: Symbol,
+        memo_text: String,
+    ) -> Result<SinkReceipt, SinkError> {
+        consume_inventory(env, &project_id, amount)?;
+        record_usage(env, &funder, amount);
+        let retirement_id = record_retirement(
+            env, funder.clone(), recipient.clone(), amount, project_id.clone(), memo_text.clone()
+        );
+        publish_sink_event(env, SinkEventData {
+            version: SINK_EVENT_VERSION,
+            retirement_id,
+            funder,
+            recipient,
+            requested_amount,
+            amount,
+            fee,
+            project_id: project_id.clone(),
+            memo_text,
+        });
+        Ok(SinkReceipt {
+            retirement_id,
+            requested_amount,
+            amount,
+            remainder: requested_amount - amount,
+            fee,
+            project_id,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        })
+    }
+
+    /// Builds the receipt of a sink in the Mercury build, which neither validates nor records the sink.
+    #[cfg(feature = "mercury")]
+    fn mercury_receipt(env: &Env, amount: i64, project_id: &Symbol) -> Result<SinkReceipt, SinkError> {
+        let quantized_amount = Self::quantize_amount(env, amount)?;
+        Ok(SinkReceipt {
+            retirement_id: 0,
+            requested_amount: amount,
+            amount: quantized_amount,
+            remainder: amount - quantized_amount,
+            fee: compute_fee(env, quantized_amount),
+            project_id: project_id.clone(),
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        })
     }
 }
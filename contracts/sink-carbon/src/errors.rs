@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, Env, InvokeError};
+use soroban_sdk::{contracterror, Env, Error, InvokeError};
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -16,6 +16,74 @@ pub enum SinkError {
     AccountOrTrustlineMissing = 1070,
     /// The trustline limit has been reached for the recipient.
     TrustlineLimitReached = 1071,
+    /// No retirement record exists for the given ID.
+    RetirementNotFound = 1072,
+    /// The project ID is not present in the project registry.
+    UnknownProject = 1073,
+    /// The project has been delisted from the project registry.
+    ProjectDelisted = 1074,
+    /// The project is already listed in the project registry.
+    ProjectAlreadyListed = 1075,
+    /// The project's available inventory is insufficient for the sink amount.
+    ProjectInventoryExhausted = 1076,
+    /// The batch does not contain any items.
+    EmptyBatch = 1077,
+    /// The batch contains more items than can be processed in a single invocation.
+    BatchTooLarge = 1078,
+    /// The funder's CARBON allowance for this contract is insufficient.
+    InsufficientAllowance = 1079,
+    /// The operator has not been approved by the funder.
+    OperatorNotApproved = 1080,
+    /// The funder's CARBON balance has been deauthorized (frozen) by the CARBON issuer.
+    FunderBalanceDeauthorized = 1081,
+    /// The recipient's CarbonSINK balance could not be authorized for the mint.
+    RecipientBalanceDeauthorized = 1082,
+    /// This contract is not the admin of the CarbonSINK SAC, so it cannot authorize or mint.
+    NotCarbonSinkAdmin = 1083,
+    /// The CARBON SAC returned an error that has no more specific `SinkError`.
+    CarbonSacError = 1084,
+    /// The CarbonSINK SAC returned an error that has no more specific `SinkError`.
+    CarbonSinkSacError = 1085,
+    /// A SAC invocation failed without returning a contract error, e.g. because of a failed authorization.
+    SacInvocationFailed = 1086,
+    /// The protocol fee exceeds the maximum fee.
+    FeeTooHigh = 1087,
+    /// The amount is not a whole multiple of the quantization unit, while the rounding policy is strict.
+    AmountNotAligned = 1088,
+    /// There is no pending admin proposal to accept or cancel.
+    NoPendingAdmin = 1089,
+    /// The caller is neither the admin nor holds the role that is required for this function.
+    Unauthorized = 1090,
+    /// The stored schema version is newer than this build of the contract supports.
+    UnsupportedSchemaVersion = 1091,
+    /// The operation is subject to the timelock, so it must be scheduled with `schedule_op`.
+    OperationTimelocked = 1092,
+    /// No scheduled operation exists for the given ID.
+    OperationNotFound = 1093,
+    /// The timelock delay of the scheduled operation has not yet elapsed.
+    OperationNotReady = 1094,
+    /// The timelock delay exceeds the maximum delay.
+    TimelockDelayTooLong = 1095,
+    /// The contract is deactivated and has a successor; call `get_contract_successor` to find the current contract.
+    ContractSuperseded = 1096,
+    /// The successor chain is longer than the maximum number of hops that is followed.
+    SuccessorChainTooLong = 1097,
+    /// The successor chain leads back to a contract that has already been visited.
+    SuccessorCycle = 1098,
+    /// A contract in the successor chain is not a SinkContract.
+    SuccessorDeadEnd = 1099,
+    /// A TTL threshold exceeds its extension amount, or an extension amount is outside the network limits.
+    InvalidTtlPolicy = 1100,
+    /// The quantized amount exceeds the maximum sink amount.
+    AmountTooHigh = 1101,
+    /// The sink would exceed the amount that the funder can sink within the rate limit window.
+    RateLimited = 1102,
+    /// The rate limit window is zero or exceeds the maximum window, or the cap is negative.
+    InvalidRateLimit = 1103,
+    /// The funder or recipient is on the denylist, or not on the allowlist while allowlist-only mode is enabled.
+    AddressBlocked = 1104,
+    /// The recipient is this contract or one of the asset contracts, which cannot hold CarbonSINK on anyone's behalf.
+    InvalidRecipient = 1105,
 }
 
 #[contracterror]
@@ -37,6 +105,50 @@ pub enum SACError {
     TrustlineMissingError = 13,
 }
 
+/// Maps an error of the CARBON SAC's `burn`, `burn_from`, `transfer` and `transfer_from` functions to a `SinkError`.
+pub fn map_burn_error(err: Error) -> SinkError {
+    match SACError::try_from(err) {
+        // most likely the funder's CARBON balance is too low
+        Ok(SACError::BalanceError) => SinkError::InsufficientBalance,
+        // burn internals check the trustline; account is only checked for native transfer
+        Ok(SACError::TrustlineMissingError) | Ok(SACError::AccountMissingError) => {
+            SinkError::AccountOrTrustlineMissing
+        }
+        // the funder's allowance for this contract is too low or has expired
+        Ok(SACError::AllowanceError) => SinkError::InsufficientAllowance,
+        Ok(SACError::BalanceDeauthorizedError) => SinkError::FunderBalanceDeauthorized,
+        Ok(SACError::NegativeAmountError) => SinkError::NegativeAmount,
+        _ => SinkError::CarbonSacError,
+    }
+}
+
+/// Maps an error of the CarbonSINK SAC's `set_authorized` function to a `SinkError`.
+pub fn map_authorize_error(err: Error) -> SinkError {
+    match SACError::try_from(err) {
+        // `set_authorization` reads the trustline entry, not the account entry
+        Ok(SACError::TrustlineMissingError) | Ok(SACError::AccountMissingError) => {
+            SinkError::AccountOrTrustlineMissing
+        }
+        Ok(SACError::UnauthorizedError) | Ok(SACError::AuthenticationError) => SinkError::NotCarbonSinkAdmin,
+        _ => SinkError::CarbonSinkSacError,
+    }
+}
+
+/// Maps an error of the CarbonSINK SAC's `mint` function to a `SinkError`.
+pub fn map_mint_error(err: Error) -> SinkError {
+    match SACError::try_from(err) {
+        // the recipient's balance would exceed its trustline limit
+        Ok(SACError::BalanceError) | Ok(SACError::OverflowError) => SinkError::TrustlineLimitReached,
+        Ok(SACError::TrustlineMissingError) | Ok(SACError::AccountMissingError) => {
+            SinkError::AccountOrTrustlineMissing
+        }
+        Ok(SACError::BalanceDeauthorizedError) => SinkError::RecipientBalanceDeauthorized,
+        Ok(SACError::UnauthorizedError) | Ok(SACError::AuthenticationError) => SinkError::NotCarbonSinkAdmin,
+        Ok(SACError::NegativeAmountError) => SinkError::NegativeAmount,
+        _ => SinkError::CarbonSinkSacError,
+    }
+}
+
 pub fn publish_sac_error(env: &Env, sub_topic: &str, error_code: u32) {
     env.events().publish(
         ("sink_carbon", sub_topic),
@@ -0,0 +1,707 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, String, Symbol, Val};
+
+use crate::limits::RateLimit;
+use crate::roles::Role;
+use crate::screening::ScreeningList;
+use crate::storage_types::{QuantizationUnit, RoundingPolicy, TtlPolicy};
+use crate::timelock::{AdminOp, ScheduledOp};
+
+/// The version of the `SinkEventData` layout, bumped whenever its fields change.
+pub(crate) const SINK_EVENT_VERSION: u32 = 1;
+
+/// The data of the `("sink_carbon", "sink")` event that is published for every successful sink.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SinkEventData {
+    pub version: u32,
+    pub retirement_id: u64,
+    pub funder: Address,
+    pub recipient: Address,
+    pub requested_amount: i64,
+    pub amount: i64,
+    pub fee: i64,
+    pub project_id: Symbol,
+    pub memo_text: String,
+}
+
+pub fn publish_sink_event(env: &Env, data: SinkEventData) {
+    env.events().publish(
+        ("sink_carbon", "sink"),
+        data
+    );
+}
+
+/// The data of the `("sink_carbon", "forward")` event that is published when a superseded contract
+/// forwards a sink to its successor. The sink event itself is published by the successor.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ForwardEventData {
+    pub successor: Address,
+    pub retirement_id: u64,
+    pub funder: Address,
+    pub recipient: Address,
+}
+
+pub fn publish_forward_event(env: &Env, data: ForwardEventData) {
+    env.events().publish(
+        ("sink_carbon", "forward"),
+        data
+    );
+}
+
+/// The data of the `("sink_carbon", "admin", "set_minimum_sink_amount")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MinimumChangedEvent {
+    pub admin: Address,
+    pub old_minimum: i64,
+    pub new_minimum: i64,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_contract_successor")` event.
+/// An unset successor is represented by the address of this contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SuccessorChangedEvent {
+    pub admin: Address,
+    pub old_successor: Address,
+    pub new_successor: Address,
+}
+
+/// The data of the `("sink_carbon", "admin", "activate")` and `("sink_carbon", "admin", "deactivate")` events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ActiveChangedEvent {
+    pub admin: Address,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", "reset_admin")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminResetEvent {
+    pub admin: Address,
+    pub old_sac_admin: Address,
+    pub new_sac_admin: Address,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", <fn_name>)` events of the project registry functions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProjectChangedEvent {
+    pub admin: Address,
+    pub project_id: Symbol,
+    pub old_is_listed: bool,
+    pub new_is_listed: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", "add_project_inventory")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InventoryChangedEvent {
+    pub admin: Address,
+    pub project_id: Symbol,
+    pub old_inventory: i128,
+    pub new_inventory: i128,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_fee_bps")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeChangedEvent {
+    pub admin: Address,
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_treasury")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TreasuryChangedEvent {
+    pub admin: Address,
+    pub old_treasury: Address,
+    pub new_treasury: Address,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_quantization")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct QuantizationChangedEvent {
+    pub admin: Address,
+    pub old_unit: QuantizationUnit,
+    pub new_unit: QuantizationUnit,
+    pub old_rounding: RoundingPolicy,
+    pub new_rounding: RoundingPolicy,
+}
+
+/// The data of the `("sink_carbon", "admin", "propose_admin")` and
+/// `("sink_carbon", "admin", "cancel_admin_proposal")` events.
+/// No pending admin is represented by the address of this contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminProposalChangedEvent {
+    pub admin: Address,
+    pub old_pending_admin: Address,
+    pub new_pending_admin: Address,
+}
+
+/// The data of the `("sink_carbon", "admin", "accept_admin")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AdminChangedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+/// The data of the `("sink_carbon", "admin", "grant_role")` and `("sink_carbon", "admin", "revoke_role")` events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RoleChangedEvent {
+    pub admin: Address,
+    pub role: Role,
+    pub account: Address,
+    pub old_has_role: bool,
+    pub new_has_role: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", "upgrade")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UpgradeEvent {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub schema_version: u32,
+}
+
+/// The data of the `("sink_carbon", "admin", "migrate")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MigrateEvent {
+    pub admin: Address,
+    pub old_schema_version: u32,
+    pub new_schema_version: u32,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_timelock_delay")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TimelockDelayChangedEvent {
+    pub admin: Address,
+    pub old_delay: u32,
+    pub new_delay: u32,
+}
+
+/// The data of the `("sink_carbon", "admin", "schedule_op")`, `("sink_carbon", "admin", "execute_op")` and
+/// `("sink_carbon", "admin", "cancel_op")` events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ScheduledOpEvent {
+    pub admin: Address,
+    pub op_id: u64,
+    pub op: AdminOp,
+    pub ready_ledger: u32,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_forwarding")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ForwardingChangedEvent {
+    pub admin: Address,
+    pub old_enabled: bool,
+    pub new_enabled: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_ttl_policy")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TtlPolicyChangedEvent {
+    pub admin: Address,
+    pub old_policy: TtlPolicy,
+    pub new_policy: TtlPolicy,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_maximum_sink_amount")` event.
+/// An unset maximum is represented by `None`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MaximumChangedEvent {
+    pub admin: Address,
+    pub old_maximum: Option<i64>,
+    pub new_maximum: Option<i64>,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_rate_limit")` event.
+/// An unset rate limit is represented by a window of 0 ledgers and a cap of 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RateLimitChangedEvent {
+    pub admin: Address,
+    pub old_window_ledgers: u32,
+    pub new_window_ledgers: u32,
+    pub old_max_amount: i128,
+    pub new_max_amount: i128,
+}
+
+/// The data of the `("sink_carbon", "admin", "add_to_list")` and `("sink_carbon", "admin", "remove_from_list")` events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ListChangedEvent {
+    pub admin: Address,
+    pub list: ScreeningList,
+    pub account: Address,
+    pub old_listed: bool,
+    pub new_listed: bool,
+}
+
+/// The data of the `("sink_carbon", "admin", "set_allowlist_only")` event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllowlistOnlyChangedEvent {
+    pub admin: Address,
+    pub old_enabled: bool,
+    pub new_enabled: bool,
+}
+
+/// Publishes the event of an admin function. For role-gated functions, the `admin` field of the event data
+/// holds the caller, which may be a role holder rather than the admin.
+fn publish_admin_event<D>(env: &Env, fn_name: &str, data: D)
+where
+    D: IntoVal<Env, Val>,
+{
+    env.events().publish(
+        ("sink_carbon", "admin", fn_name),
+        data
+    );
+}
+
+pub fn publish_minimum_changed(env: &Env, admin: &Address, old_minimum: i64, new_minimum: i64) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::MinimumChangedEvent {
+        admin: admin.clone(),
+        old_minimum,
+        new_minimum,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_minimum_sink_amount", MinimumChangedEvent {
+        admin: admin.clone(),
+        old_minimum,
+        new_minimum,
+    });
+}
+
+pub fn publish_successor_changed(env: &Env, admin: &Address, old_successor: &Address, new_successor: &Address) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::SuccessorChangedEvent {
+        admin: admin.clone(),
+        old_successor: old_successor.clone(),
+        new_successor: new_successor.clone(),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_contract_successor", SuccessorChangedEvent {
+        admin: admin.clone(),
+        old_successor: old_successor.clone(),
+        new_successor: new_successor.clone(),
+    });
+}
+
+pub fn publish_active_changed(env: &Env, fn_name: &str, admin: &Address, old_is_active: bool, new_is_active: bool) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::ActiveChangedEvent {
+        admin: admin.clone(),
+        old_is_active,
+        new_is_active,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, ActiveChangedEvent {
+        admin: admin.clone(),
+        old_is_active,
+        new_is_active,
+    });
+}
+
+pub fn publish_admin_reset(
+    env: &Env, admin: &Address, old_sac_admin: &Address, old_is_active: bool, new_is_active: bool
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::AdminResetEvent {
+        admin: admin.clone(),
+        old_sac_admin: old_sac_admin.clone(),
+        new_sac_admin: admin.clone(),
+        old_is_active,
+        new_is_active,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "reset_admin", AdminResetEvent {
+        admin: admin.clone(),
+        old_sac_admin: old_sac_admin.clone(),
+        new_sac_admin: admin.clone(),
+        old_is_active,
+        new_is_active,
+    });
+}
+
+pub fn publish_project_changed(
+    env: &Env, fn_name: &str, admin: &Address, project_id: &Symbol, old_is_listed: bool, new_is_listed: bool
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::ProjectChangedEvent {
+        admin: admin.clone(),
+        project_id: project_id.clone(),
+        old_is_listed,
+        new_is_listed,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, ProjectChangedEvent {
+        admin: admin.clone(),
+        project_id: project_id.clone(),
+        old_is_listed,
+        new_is_listed,
+    });
+}
+
+pub fn publish_inventory_changed(
+    env: &Env, admin: &Address, project_id: &Symbol, old_inventory: i128, new_inventory: i128
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::InventoryChangedEvent {
+        admin: admin.clone(),
+        project_id: project_id.clone(),
+        old_inventory,
+        new_inventory,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "add_project_inventory", InventoryChangedEvent {
+        admin: admin.clone(),
+        project_id: project_id.clone(),
+        old_inventory,
+        new_inventory,
+    });
+}
+
+pub fn publish_fee_changed(env: &Env, admin: &Address, old_fee_bps: u32, new_fee_bps: u32) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::FeeChangedEvent {
+        admin: admin.clone(),
+        old_fee_bps,
+        new_fee_bps,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_fee_bps", FeeChangedEvent {
+        admin: admin.clone(),
+        old_fee_bps,
+        new_fee_bps,
+    });
+}
+
+pub fn publish_treasury_changed(env: &Env, admin: &Address, old_treasury: &Address, new_treasury: &Address) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::TreasuryChangedEvent {
+        admin: admin.clone(),
+        old_treasury: old_treasury.clone(),
+        new_treasury: new_treasury.clone(),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_treasury", TreasuryChangedEvent {
+        admin: admin.clone(),
+        old_treasury: old_treasury.clone(),
+        new_treasury: new_treasury.clone(),
+    });
+}
+
+pub fn publish_quantization_changed(
+    env: &Env,
+    admin: &Address,
+    old_unit: QuantizationUnit,
+    new_unit: QuantizationUnit,
+    old_rounding: RoundingPolicy,
+    new_rounding: RoundingPolicy,
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::QuantizationChangedEvent {
+        admin: admin.clone(),
+        old_unit: old_unit as u32,
+        new_unit: new_unit as u32,
+        old_rounding: old_rounding as u32,
+        new_rounding: new_rounding as u32,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_quantization", QuantizationChangedEvent {
+        admin: admin.clone(),
+        old_unit,
+        new_unit,
+        old_rounding,
+        new_rounding,
+    });
+}
+
+pub fn publish_admin_proposal_changed(
+    env: &Env, fn_name: &str, admin: &Address, old_pending_admin: &Address, new_pending_admin: &Address
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::AdminProposalChangedEvent {
+        admin: admin.clone(),
+        old_pending_admin: old_pending_admin.clone(),
+        new_pending_admin: new_pending_admin.clone(),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, AdminProposalChangedEvent {
+        admin: admin.clone(),
+        old_pending_admin: old_pending_admin.clone(),
+        new_pending_admin: new_pending_admin.clone(),
+    });
+}
+
+pub fn publish_admin_changed(env: &Env, old_admin: &Address, new_admin: &Address) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::AdminChangedEvent {
+        old_admin: old_admin.clone(),
+        new_admin: new_admin.clone(),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "accept_admin", AdminChangedEvent {
+        old_admin: old_admin.clone(),
+        new_admin: new_admin.clone(),
+    });
+}
+
+pub fn publish_role_changed(
+    env: &Env, fn_name: &str, admin: &Address, role: Role, account: &Address, old_has_role: bool, new_has_role: bool
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::RoleChangedEvent {
+        admin: admin.clone(),
+        role: role as u32,
+        account: account.clone(),
+        old_has_role,
+        new_has_role,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, RoleChangedEvent {
+        admin: admin.clone(),
+        role,
+        account: account.clone(),
+        old_has_role,
+        new_has_role,
+    });
+}
+
+pub fn publish_upgrade(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>, schema_version: u32) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::UpgradeEvent {
+        admin: admin.clone(),
+        new_wasm_hash: new_wasm_hash.clone(),
+        schema_version,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "upgrade", UpgradeEvent {
+        admin: admin.clone(),
+        new_wasm_hash: new_wasm_hash.clone(),
+        schema_version,
+    });
+}
+
+pub fn publish_migrate(env: &Env, admin: &Address, old_schema_version: u32, new_schema_version: u32) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::MigrateEvent {
+        admin: admin.clone(),
+        old_schema_version,
+        new_schema_version,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "migrate", MigrateEvent {
+        admin: admin.clone(),
+        old_schema_version,
+        new_schema_version,
+    });
+}
+
+pub fn publish_timelock_delay_changed(env: &Env, admin: &Address, old_delay: u32, new_delay: u32) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::TimelockDelayChangedEvent {
+        admin: admin.clone(),
+        old_delay,
+        new_delay,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_timelock_delay", TimelockDelayChangedEvent {
+        admin: admin.clone(),
+        old_delay,
+        new_delay,
+    });
+}
+
+pub fn publish_scheduled_op(env: &Env, fn_name: &str, admin: &Address, scheduled: &ScheduledOp) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::ScheduledOpEvent {
+        admin: admin.clone(),
+        op_id: scheduled.id,
+        op_name: Symbol::new(env, scheduled.op.fn_name()),
+        ready_ledger: scheduled.ready_ledger,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, ScheduledOpEvent {
+        admin: admin.clone(),
+        op_id: scheduled.id,
+        op: scheduled.op.clone(),
+        ready_ledger: scheduled.ready_ledger,
+    });
+}
+
+pub fn publish_forwarding_changed(env: &Env, admin: &Address, old_enabled: bool, new_enabled: bool) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::ForwardingChangedEvent {
+        admin: admin.clone(),
+        old_enabled,
+        new_enabled,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_forwarding", ForwardingChangedEvent {
+        admin: admin.clone(),
+        old_enabled,
+        new_enabled,
+    });
+}
+
+pub fn publish_ttl_policy_changed(env: &Env, admin: &Address, old_policy: &TtlPolicy, new_policy: &TtlPolicy) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::TtlPolicyChangedEvent {
+        admin: admin.clone(),
+        old_instance_threshold: old_policy.instance_threshold,
+        new_instance_threshold: new_policy.instance_threshold,
+        old_instance_extend_amount: old_policy.instance_extend_amount,
+        new_instance_extend_amount: new_policy.instance_extend_amount,
+        old_persistent_threshold: old_policy.persistent_threshold,
+        new_persistent_threshold: new_policy.persistent_threshold,
+        old_persistent_extend_amount: old_policy.persistent_extend_amount,
+        new_persistent_extend_amount: new_policy.persistent_extend_amount,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_ttl_policy", TtlPolicyChangedEvent {
+        admin: admin.clone(),
+        old_policy: old_policy.clone(),
+        new_policy: new_policy.clone(),
+    });
+}
+
+pub fn publish_maximum_changed(env: &Env, admin: &Address, old_maximum: Option<i64>, new_maximum: Option<i64>) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::MaximumChangedEvent {
+        admin: admin.clone(),
+        old_maximum: old_maximum.unwrap_or(0),
+        new_maximum: new_maximum.unwrap_or(0),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_maximum_sink_amount", MaximumChangedEvent {
+        admin: admin.clone(),
+        old_maximum,
+        new_maximum,
+    });
+}
+
+pub fn publish_rate_limit_changed(
+    env: &Env, admin: &Address, old_limit: &Option<RateLimit>, new_limit: &Option<RateLimit>
+) {
+    let old_window_ledgers = old_limit.as_ref().map_or(0, |limit| limit.window_ledgers);
+    let new_window_ledgers = new_limit.as_ref().map_or(0, |limit| limit.window_ledgers);
+    let old_max_amount = old_limit.as_ref().map_or(0, |limit| limit.max_amount);
+    let new_max_amount = new_limit.as_ref().map_or(0, |limit| limit.max_amount);
+    #[cfg(feature = "mercury")]
+    crate::retroshades::RateLimitChangedEvent {
+        admin: admin.clone(),
+        old_window_ledgers,
+        new_window_ledgers,
+        old_max_amount,
+        new_max_amount,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_rate_limit", RateLimitChangedEvent {
+        admin: admin.clone(),
+        old_window_ledgers,
+        new_window_ledgers,
+        old_max_amount,
+        new_max_amount,
+    });
+}
+
+pub fn publish_list_changed(
+    env: &Env, fn_name: &str, admin: &Address, list: ScreeningList, account: &Address, old_listed: bool, new_listed: bool
+) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::ListChangedEvent {
+        admin: admin.clone(),
+        list: list as u32,
+        account: account.clone(),
+        old_listed,
+        new_listed,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, fn_name, ListChangedEvent {
+        admin: admin.clone(),
+        list,
+        account: account.clone(),
+        old_listed,
+        new_listed,
+    });
+}
+
+pub fn publish_allowlist_only_changed(env: &Env, admin: &Address, old_enabled: bool, new_enabled: bool) {
+    #[cfg(feature = "mercury")]
+    crate::retroshades::AllowlistOnlyChangedEvent {
+        admin: admin.clone(),
+        old_enabled,
+        new_enabled,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+    .emit(env);
+    publish_admin_event(env, "set_allowlist_only", AllowlistOnlyChangedEvent {
+        admin: admin.clone(),
+        old_enabled,
+        new_enabled,
+    });
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage_types::DataKey;
+
+/// The maximum protocol fee that the admin can set, in basis points (10%).
+pub(crate) const MAX_FEE_BPS: u32 = 1_000;
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Reads the protocol fee in basis points, which is zero unless the admin has set it.
+pub fn read_fee_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+}
+
+pub fn write_fee_bps(env: &Env, fee_bps: u32) {
+    env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+}
+
+/// Reads the address that receives the protocol fees, which defaults to the contract admin.
+pub fn read_treasury(env: &Env) -> Address {
+    match env.storage().instance().get(&DataKey::Treasury) {
+        Some(treasury) => treasury,
+        None => env.storage().instance().get(&DataKey::Admin).unwrap(),
+    }
+}
+
+pub fn write_treasury(env: &Env, treasury: &Address) {
+    env.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+/// Computes the protocol fee for sinking the quantized `amount`. The fee is charged on top of `amount`,
+/// so the funder spends `amount + fee` CARBON. It is rounded down to whole decigrams.
+pub fn compute_fee(env: &Env, amount: i64) -> i64 {
+    (amount as i128 * read_fee_bps(env) as i128 / BPS_DENOMINATOR) as i64
+}
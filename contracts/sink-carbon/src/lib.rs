@@ -2,7 +2,19 @@
 
 mod contract;
 mod errors;
+mod events;
+mod fees;
+mod limits;
+mod migrations;
+mod projects;
+mod retirements;
 mod retroshades;
+mod roles;
+mod sac;
+mod screening;
 mod storage_types;
+mod successor;
+mod timelock;
+mod ttl;
 mod utils;
 mod tests;
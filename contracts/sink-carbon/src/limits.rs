@@ -0,0 +1,131 @@
+use soroban_sdk::{contracttype, Address, Env, Map};
+
+use crate::errors::SinkError;
+use crate::storage_types::{DataKey, DAY_IN_LEDGERS};
+
+/// The maximum length of a rate limit window. The usage of a funder is kept in temporary storage
+/// for the length of the window, so it must stay well within the maximum TTL of the network.
+pub(crate) const MAX_RATE_LIMIT_WINDOW: u32 = 30 * DAY_IN_LEDGERS;
+
+/// The number of buckets that the usage of a funder is recorded in per rate limit window.
+/// A sunk amount counts towards the usage until its whole bucket has left the window,
+/// i.e. for up to a bucket length longer than the window.
+pub(crate) const RATE_LIMIT_BUCKETS: u32 = 24;
+
+/// A cap on the amount that a single funder can sink within a window of ledgers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RateLimit {
+    /// The length of the window in ledgers, e.g. 17280 for about a day.
+    pub window_ledgers: u32,
+    /// The maximum amount in CARBON decigrams that a funder can sink within the window.
+    pub max_amount: i128,
+}
+
+impl RateLimit {
+    /// Checks that the window is between one ledger and the maximum window, and that the cap is non-negative.
+    pub fn validate(&self) -> Result<(), SinkError> {
+        if self.window_ledgers == 0 || self.window_ledgers > MAX_RATE_LIMIT_WINDOW || self.max_amount < 0 {
+            return Err(SinkError::InvalidRateLimit);
+        }
+        Ok(())
+    }
+
+    /// The number of ledgers of each usage bucket, at least one.
+    fn bucket_ledgers(&self) -> u32 {
+        self.window_ledgers.div_ceil(RATE_LIMIT_BUCKETS)
+    }
+
+    /// The first ledger of the bucket that contains `sequence`.
+    fn bucket_start(&self, sequence: u32) -> u32 {
+        sequence - sequence % self.bucket_ledgers()
+    }
+
+    /// The first ledger of the oldest bucket that still overlaps the window ending with `sequence`.
+    fn oldest_bucket_start(&self, sequence: u32) -> u32 {
+        self.bucket_start((sequence + 1).saturating_sub(self.window_ledgers))
+    }
+}
+
+/// Reads the maximum amount of a single sink, or `None` if the admin has not set a maximum.
+pub fn read_sink_maximum(env: &Env) -> Option<i64> {
+    env.storage().instance().get(&DataKey::SinkMaximum)
+}
+
+pub fn write_sink_maximum(env: &Env, maximum: Option<i64>) {
+    match maximum {
+        Some(maximum) => env.storage().instance().set(&DataKey::SinkMaximum, &maximum),
+        None => env.storage().instance().remove(&DataKey::SinkMaximum),
+    }
+}
+
+/// Reads the rate limit per funder, or `None` if the admin has not set a rate limit.
+pub fn read_rate_limit(env: &Env) -> Option<RateLimit> {
+    env.storage().instance().get(&DataKey::RateLimit)
+}
+
+pub fn write_rate_limit(env: &Env, limit: &Option<RateLimit>) {
+    match limit {
+        Some(limit) => env.storage().instance().set(&DataKey::RateLimit, limit),
+        None => env.storage().instance().remove(&DataKey::RateLimit),
+    }
+}
+
+/// Checks that the quantized `amount` does not exceed the maximum sink amount.
+pub fn check_maximum(env: &Env, amount: i64) -> Result<(), SinkError> {
+    match read_sink_maximum(env) {
+        Some(maximum) if amount > maximum => Err(SinkError::AmountTooHigh),
+        _ => Ok(()),
+    }
+}
+
+/// Reads the buckets of `funder` that overlap the window ending with the current ledger,
+/// keyed by their first ledger.
+fn read_buckets(env: &Env, funder: &Address, limit: &RateLimit) -> Map<u32, i128> {
+    let oldest = limit.oldest_bucket_start(env.ledger().sequence());
+    let mut buckets: Map<u32, i128> = env.storage().temporary()
+        .get(&DataKey::FunderUsage(funder.clone()))
+        .unwrap_or(Map::new(env));
+    for start in buckets.keys() {
+        if start < oldest {
+            buckets.remove(start);
+        }
+    }
+    buckets
+}
+
+/// Reads the usage of `funder` in the sliding window of `window_ledgers` that ends with the current ledger.
+/// The usage is recorded in buckets, so an amount counts for up to a bucket length longer than the window,
+/// but never less: a funder can never sink more than the cap within any window.
+pub fn read_usage(env: &Env, funder: &Address, limit: &RateLimit) -> i128 {
+    read_buckets(env, funder, limit).values().iter().sum()
+}
+
+/// Checks that sinking the quantized `amount` keeps `funder` within the rate limit, if one is set.
+pub fn check_rate_limit(env: &Env, funder: &Address, amount: i64) -> Result<(), SinkError> {
+    match read_rate_limit(env) {
+        Some(limit) if read_usage(env, funder, &limit) + amount as i128 > limit.max_amount => {
+            Err(SinkError::RateLimited)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Adds the quantized `amount` to the bucket of the current ledger in the usage of `funder`,
+/// if a rate limit is set, and drops the buckets that have left the window. The usage entry
+/// expires from temporary storage when the current bucket leaves the window.
+pub fn record_usage(env: &Env, funder: &Address, amount: i64) {
+    let limit = match read_rate_limit(env) {
+        Some(limit) => limit,
+        None => return,
+    };
+    let sequence = env.ledger().sequence();
+    let start = limit.bucket_start(sequence);
+    let mut buckets = read_buckets(env, funder, &limit);
+    buckets.set(start, buckets.get(start).unwrap_or(0) + amount as i128);
+
+    let key = DataKey::FunderUsage(funder.clone());
+    let remaining = start + limit.bucket_ledgers() + limit.window_ledgers - 1 - sequence;
+    env.storage().temporary().set(&key, &buckets);
+    env.storage().temporary().extend_ttl(&key, remaining, remaining);
+}
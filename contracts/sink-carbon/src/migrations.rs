@@ -0,0 +1,56 @@
+use soroban_sdk::{Env, IntoVal, Val};
+
+use crate::errors::SinkError;
+use crate::storage_types::{DataKey, QuantizationUnit, RoundingPolicy};
+
+/// The version of the storage layout that this build of the contract reads and writes.
+pub(crate) const SCHEMA_VERSION: u32 = 2;
+/// The version of the storage layout of contracts that predate schema versioning (0.4.x),
+/// which have no `SchemaVersion` entry.
+pub(crate) const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+pub fn read_schema_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+pub fn write_schema_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::SchemaVersion, &version);
+}
+
+/// Migrates the storage layout step by step up to `SCHEMA_VERSION`, and returns the old version.
+/// Every step is only applied once, so calling this again is a no-op.
+pub fn migrate_storage(env: &Env) -> Result<u32, SinkError> {
+    let old_version = read_schema_version(env);
+    if old_version > SCHEMA_VERSION {
+        // a downgrade to an earlier build cannot be migrated
+        return Err(SinkError::UnsupportedSchemaVersion);
+    }
+    let mut version = old_version;
+    while version < SCHEMA_VERSION {
+        match version {
+            1 => migrate_v1_to_v2(env),
+            _ => return Err(SinkError::UnsupportedSchemaVersion),
+        }
+        version += 1;
+        write_schema_version(env, version);
+    }
+    Ok(old_version)
+}
+
+/// Version 1 (0.4.x) only stores the admin, asset IDs, active flag, minimum and successor.
+/// Version 2 adds settings that are read with a default if absent; store those defaults explicitly,
+/// so that every setting of a migrated contract is present in its instance storage.
+fn migrate_v1_to_v2(env: &Env) {
+    set_if_absent(env, &DataKey::FeeBps, 0_u32);
+    set_if_absent(env, &DataKey::QuantizationUnit, QuantizationUnit::Kilogram);
+    set_if_absent(env, &DataKey::RoundingPolicy, RoundingPolicy::Truncate);
+}
+
+fn set_if_absent<V>(env: &Env, key: &DataKey, val: V)
+where
+    V: IntoVal<Env, Val>,
+{
+    if !env.storage().instance().has(key) {
+        env.storage().instance().set(key, &val);
+    }
+}
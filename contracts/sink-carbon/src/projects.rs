@@ -0,0 +1,131 @@
+use soroban_sdk::{Env, Symbol, Vec};
+
+use crate::errors::SinkError;
+use crate::retirements::MAX_PAGE_SIZE;
+use crate::storage_types::{extend_persistent_ttl, DataKey, Project, ProjectInfo};
+
+/// Reads a project from the registry and extends its TTL if it exists.
+pub fn read_project(env: &Env, project_id: &Symbol) -> Option<Project> {
+    let key = DataKey::Project(project_id.clone());
+    let project: Option<Project> = env.storage().persistent().get(&key);
+    if project.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    project
+}
+
+pub fn write_project(env: &Env, project: &Project) {
+    let key = DataKey::Project(project.project_id.clone());
+    env.storage().persistent().set(&key, project);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Lists a new project, or relists a project that was delisted earlier.
+pub fn register_project(env: &Env, project_id: Symbol, info: ProjectInfo) -> Result<(), SinkError> {
+    match read_project(env, &project_id) {
+        Some(project) if project.is_listed => return Err(SinkError::ProjectAlreadyListed),
+        Some(_) => {}
+        None => {
+            // new projects are appended to the registry listing
+            let mut project_ids = read_project_ids(env);
+            project_ids.push_back(project_id.clone());
+            env.storage().persistent().set(&DataKey::ProjectList, &project_ids);
+            extend_persistent_ttl(env, &DataKey::ProjectList);
+        }
+    }
+    write_project(env, &Project { project_id, info, is_listed: true });
+    Ok(())
+}
+
+/// Checks that `project_id` refers to a listed project, so that it can be sunk against.
+pub fn check_project_listed(env: &Env, project_id: &Symbol) -> Result<Project, SinkError> {
+    match read_project(env, project_id) {
+        Some(project) if project.is_listed => Ok(project),
+        Some(_) => Err(SinkError::ProjectDelisted),
+        None => Err(SinkError::UnknownProject),
+    }
+}
+
+/// Reads the available inventory of a project and extends its TTL if it exists.
+pub fn read_inventory(env: &Env, project_id: &Symbol) -> i128 {
+    let key = DataKey::ProjectInventory(project_id.clone());
+    match env.storage().persistent().get(&key) {
+        Some(inventory) => {
+            extend_persistent_ttl(env, &key);
+            inventory
+        }
+        None => 0,
+    }
+}
+
+pub fn write_inventory(env: &Env, project_id: &Symbol, inventory: i128) {
+    let key = DataKey::ProjectInventory(project_id.clone());
+    env.storage().persistent().set(&key, &inventory);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Checks that the available inventory of a project can back `amount`.
+pub fn check_inventory(env: &Env, project_id: &Symbol, amount: i64) -> Result<i128, SinkError> {
+    let inventory = read_inventory(env, project_id);
+    if inventory < amount as i128 {
+        return Err(SinkError::ProjectInventoryExhausted);
+    }
+    Ok(inventory)
+}
+
+/// Deducts `amount` from the available inventory of a project.
+pub fn consume_inventory(env: &Env, project_id: &Symbol, amount: i64) -> Result<(), SinkError> {
+    let inventory = check_inventory(env, project_id, amount)?;
+    write_inventory(env, project_id, inventory - amount as i128);
+    Ok(())
+}
+
+/// Returns a page of registered projects, in order of registration.
+pub fn read_projects(env: &Env, cursor: u32, limit: u32) -> Vec<Project> {
+    let project_ids = read_project_ids(env);
+    let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(project_ids.len());
+
+    let mut page = Vec::new(env);
+    for position in cursor..end {
+        if let Some(project) = read_project(env, &project_ids.get_unchecked(position)) {
+            page.push_back(project);
+        }
+    }
+    page
+}
+
+/// Reads the number of projects in the registry, including delisted projects.
+pub fn read_project_count(env: &Env) -> u32 {
+    read_project_ids(env).len()
+}
+
+/// Extends the TTL of the project registry listing and of up to `limit` registered projects,
+/// with their inventory and total, starting at position `cursor` of the listing.
+pub fn extend_registry_ttl(env: &Env, cursor: u32, limit: u32) {
+    let project_ids = read_project_ids(env);
+    let end = cursor.saturating_add(limit).min(project_ids.len());
+    for position in cursor..end {
+        let project_id = project_ids.get_unchecked(position);
+        for key in [
+            DataKey::Project(project_id.clone()),
+            DataKey::ProjectInventory(project_id.clone()),
+            DataKey::ProjectTotal(project_id),
+        ] {
+            // the inventory and total are only written once a project is stocked and sunk against
+            if env.storage().persistent().has(&key) {
+                extend_persistent_ttl(env, &key);
+            }
+        }
+    }
+}
+
+fn read_project_ids(env: &Env) -> Vec<Symbol> {
+    let key = DataKey::ProjectList;
+    match env.storage().persistent().get(&key) {
+        Some(project_ids) => {
+            extend_persistent_ttl(env, &key);
+            project_ids
+        }
+        None => Vec::new(env),
+    }
+}
@@ -0,0 +1,185 @@
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::storage_types::{extend_persistent_ttl, DataKey, Retirement, SinkTotals};
+
+/// The maximum number of retirements returned by a single page of a listing.
+pub(crate) const MAX_PAGE_SIZE: u32 = 50;
+
+/// Increments the retirement counter and returns the new retirement ID.
+/// IDs start at 1, so an ID of 0 never refers to a stored retirement.
+pub fn next_retirement_id(env: &Env) -> u64 {
+    let id = get_retirement_count(env) + 1;
+    env.storage().instance().set(&DataKey::RetirementCount, &id);
+    id
+}
+
+pub fn get_retirement_count(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::RetirementCount).unwrap_or(0)
+}
+
+/// Writes a new retirement record to persistent storage, adds it to the
+/// recipient and funder indexes, updates the cumulative totals, and returns its ID.
+pub fn record_retirement(
+    env: &Env,
+    funder: Address,
+    recipient: Address,
+    amount: i64,
+    project_id: Symbol,
+    memo_text: String,
+) -> u64 {
+    let id = next_retirement_id(env);
+    add_to_totals(env, &funder, &recipient, &project_id, amount);
+
+    append_to_index(
+        env,
+        DataKey::RecipientRetirementCount(recipient.clone()),
+        |position| DataKey::RecipientRetirement(recipient.clone(), position),
+        id,
+    );
+    append_to_index(
+        env,
+        DataKey::FunderRetirementCount(funder.clone()),
+        |position| DataKey::FunderRetirement(funder.clone(), position),
+        id,
+    );
+
+    let retirement = Retirement {
+        id,
+        funder,
+        recipient,
+        amount,
+        project_id,
+        memo_text,
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    };
+    let key = DataKey::Retirement(id);
+    env.storage().persistent().set(&key, &retirement);
+    extend_persistent_ttl(env, &key);
+    id
+}
+
+/// Reads a retirement record and extends its TTL if it exists.
+pub fn read_retirement(env: &Env, id: u64) -> Option<Retirement> {
+    let key = DataKey::Retirement(id);
+    let retirement: Option<Retirement> = env.storage().persistent().get(&key);
+    if retirement.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    retirement
+}
+
+/// Returns a page of retirements for `recipient`, oldest first.
+pub fn list_by_recipient(env: &Env, recipient: &Address, cursor: u32, limit: u32) -> Vec<Retirement> {
+    list_from_index(
+        env,
+        DataKey::RecipientRetirementCount(recipient.clone()),
+        |position| DataKey::RecipientRetirement(recipient.clone(), position),
+        cursor,
+        limit,
+    )
+}
+
+/// Returns a page of retirements for `funder`, oldest first.
+pub fn list_by_funder(env: &Env, funder: &Address, cursor: u32, limit: u32) -> Vec<Retirement> {
+    list_from_index(
+        env,
+        DataKey::FunderRetirementCount(funder.clone()),
+        |position| DataKey::FunderRetirement(funder.clone(), position),
+        cursor,
+        limit,
+    )
+}
+
+/// Extends the TTL of up to `limit` retirement records, starting with the record at position `cursor`,
+/// i.e. with ID `cursor + 1`, together with the cumulative totals of their recipients and funders.
+pub fn extend_retirements_ttl(env: &Env, cursor: u32, limit: u32) {
+    let end = (cursor as u64).saturating_add(limit as u64).min(get_retirement_count(env));
+    for id in (cursor as u64 + 1)..=end {
+        if let Some(retirement) = read_retirement(env, id) {
+            for key in [
+                DataKey::RecipientTotal(retirement.recipient),
+                DataKey::FunderTotal(retirement.funder),
+            ] {
+                extend_persistent_ttl(env, &key);
+            }
+        }
+    }
+}
+
+pub fn read_totals(env: &Env) -> SinkTotals {
+    SinkTotals {
+        total_sunk: env.storage().instance().get(&DataKey::TotalSunk).unwrap_or(0),
+        sink_count: get_retirement_count(env),
+        unique_recipients: env.storage().instance().get(&DataKey::UniqueRecipients).unwrap_or(0),
+    }
+}
+
+/// Reads a cumulative total from persistent storage and extends its TTL if it exists.
+pub fn read_total(env: &Env, key: &DataKey) -> i128 {
+    match env.storage().persistent().get(key) {
+        Some(total) => {
+            extend_persistent_ttl(env, key);
+            total
+        }
+        None => 0,
+    }
+}
+
+fn add_to_totals(env: &Env, funder: &Address, recipient: &Address, project_id: &Symbol, amount: i64) {
+    let total_sunk: i128 = env.storage().instance().get(&DataKey::TotalSunk).unwrap_or(0);
+    env.storage().instance().set(&DataKey::TotalSunk, &(total_sunk + amount as i128));
+
+    // a recipient without a total has never received CarbonSINK from this contract
+    let recipient_key = DataKey::RecipientTotal(recipient.clone());
+    if !env.storage().persistent().has(&recipient_key) {
+        let unique_recipients: u64 = env.storage().instance().get(&DataKey::UniqueRecipients).unwrap_or(0);
+        env.storage().instance().set(&DataKey::UniqueRecipients, &(unique_recipients + 1));
+    }
+
+    for key in [
+        recipient_key,
+        DataKey::FunderTotal(funder.clone()),
+        DataKey::ProjectTotal(project_id.clone()),
+    ] {
+        let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(total + amount as i128));
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Appends `id` to a per-address index. Each index consists of a count entry
+/// and one entry per position, so that pages can be read without loading the whole index.
+fn append_to_index<F>(env: &Env, count_key: DataKey, position_key: F, id: u64)
+where
+    F: Fn(u32) -> DataKey,
+{
+    let position: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let key = position_key(position);
+    env.storage().persistent().set(&key, &id);
+    extend_persistent_ttl(env, &key);
+    env.storage().persistent().set(&count_key, &(position + 1));
+    extend_persistent_ttl(env, &count_key);
+}
+
+fn list_from_index<F>(env: &Env, count_key: DataKey, position_key: F, cursor: u32, limit: u32) -> Vec<Retirement>
+where
+    F: Fn(u32) -> DataKey,
+{
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut page = Vec::new(env);
+    for position in cursor..end {
+        let key = position_key(position);
+        let id: u64 = env.storage().persistent().get(&key).unwrap();
+        extend_persistent_ttl(env, &key);
+        if let Some(retirement) = read_retirement(env, id) {
+            page.push_back(retirement);
+        }
+    }
+    if cursor < end {
+        extend_persistent_ttl(env, &count_key);
+    }
+    page
+}
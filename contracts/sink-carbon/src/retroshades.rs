@@ -1,7 +1,7 @@
 #![cfg(feature = "mercury")]
 
 use retroshade_sdk::Retroshade;
-use soroban_sdk::{contracttype, Address, String, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol};
 
 #[derive(Retroshade)]
 #[contracttype]
@@ -14,3 +14,243 @@ pub struct SinkEvent {
     pub ledger: u32,
     pub timestamp: u64,
 }
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct MinimumChangedEvent {
+    pub admin: Address,
+    pub old_minimum: i64,
+    pub new_minimum: i64,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct SuccessorChangedEvent {
+    pub admin: Address,
+    pub old_successor: Address,
+    pub new_successor: Address,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct ActiveChangedEvent {
+    pub admin: Address,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct AdminResetEvent {
+    pub admin: Address,
+    pub old_sac_admin: Address,
+    pub new_sac_admin: Address,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct ProjectChangedEvent {
+    pub admin: Address,
+    pub project_id: Symbol,
+    pub old_is_listed: bool,
+    pub new_is_listed: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct InventoryChangedEvent {
+    pub admin: Address,
+    pub project_id: Symbol,
+    pub old_inventory: i128,
+    pub new_inventory: i128,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct FeeChangedEvent {
+    pub admin: Address,
+    pub old_fee_bps: u32,
+    pub new_fee_bps: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct TreasuryChangedEvent {
+    pub admin: Address,
+    pub old_treasury: Address,
+    pub new_treasury: Address,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct QuantizationChangedEvent {
+    pub admin: Address,
+    pub old_unit: u32,
+    pub new_unit: u32,
+    pub old_rounding: u32,
+    pub new_rounding: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct AdminProposalChangedEvent {
+    pub admin: Address,
+    pub old_pending_admin: Address,
+    pub new_pending_admin: Address,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct AdminChangedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct RoleChangedEvent {
+    pub admin: Address,
+    pub role: u32,
+    pub account: Address,
+    pub old_has_role: bool,
+    pub new_has_role: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct UpgradeEvent {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub schema_version: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct MigrateEvent {
+    pub admin: Address,
+    pub old_schema_version: u32,
+    pub new_schema_version: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct TimelockDelayChangedEvent {
+    pub admin: Address,
+    pub old_delay: u32,
+    pub new_delay: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct ScheduledOpEvent {
+    pub admin: Address,
+    pub op_id: u64,
+    pub op_name: Symbol,
+    pub ready_ledger: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct ForwardingChangedEvent {
+    pub admin: Address,
+    pub old_enabled: bool,
+    pub new_enabled: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct TtlPolicyChangedEvent {
+    pub admin: Address,
+    pub old_instance_threshold: u32,
+    pub new_instance_threshold: u32,
+    pub old_instance_extend_amount: u32,
+    pub new_instance_extend_amount: u32,
+    pub old_persistent_threshold: u32,
+    pub new_persistent_threshold: u32,
+    pub old_persistent_extend_amount: u32,
+    pub new_persistent_extend_amount: u32,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+// an unset maximum is represented by 0
+#[derive(Retroshade)]
+#[contracttype]
+pub struct MaximumChangedEvent {
+    pub admin: Address,
+    pub old_maximum: i64,
+    pub new_maximum: i64,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+// an unset rate limit is represented by a window of 0 ledgers
+#[derive(Retroshade)]
+#[contracttype]
+pub struct RateLimitChangedEvent {
+    pub admin: Address,
+    pub old_window_ledgers: u32,
+    pub new_window_ledgers: u32,
+    pub old_max_amount: i128,
+    pub new_max_amount: i128,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct ListChangedEvent {
+    pub admin: Address,
+    pub list: u32,
+    pub account: Address,
+    pub old_listed: bool,
+    pub new_listed: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Retroshade)]
+#[contracttype]
+pub struct AllowlistOnlyChangedEvent {
+    pub admin: Address,
+    pub old_enabled: bool,
+    pub new_enabled: bool,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
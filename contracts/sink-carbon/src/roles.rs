@@ -0,0 +1,63 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::errors::SinkError;
+use crate::storage_types::{extend_persistent_ttl, read_set_members, write_set_member, DataKey};
+
+/// A role that the admin can grant to other addresses, for routine operations that should not
+/// require the admin key. The admin implicitly holds every role.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum Role {
+    /// May `deactivate` the contract.
+    Pauser = 1,
+    /// May set the minimum sink amount, the protocol fee and the quantization.
+    ConfigManager = 2,
+    /// May set the contract successor and call `reset_admin`.
+    UpgradeManager = 3,
+}
+
+pub fn read_role(env: &Env, role: Role, account: &Address) -> bool {
+    let key = DataKey::Role(role, account.clone());
+    let granted = env.storage().persistent().has(&key);
+    if granted {
+        extend_persistent_ttl(env, &key);
+    }
+    granted
+}
+
+/// Grants `role` to `account` or revokes it. The members of each role are kept in an enumerable set,
+/// so that `extend_ttl` can reach them.
+pub fn write_role(env: &Env, role: Role, account: &Address, granted: bool) {
+    write_set_member(
+        env,
+        &DataKey::RoleCount(role),
+        |position| DataKey::RoleEntry(role, position),
+        |account: &Address| DataKey::Role(role, account.clone()),
+        account,
+        granted,
+    );
+}
+
+/// Extends the TTL of up to `limit` members of `role`, starting at position `cursor`.
+pub fn extend_role_ttl(env: &Env, role: Role, cursor: u32, limit: u32) {
+    read_set_members(
+        env,
+        &DataKey::RoleCount(role),
+        |position| DataKey::RoleEntry(role, position),
+        |account: &Address| DataKey::Role(role, account.clone()),
+        cursor,
+        limit,
+    );
+}
+
+/// Requires the authorization of `caller`, which must be the admin or hold `role`.
+pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), SinkError> {
+    caller.require_auth();
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    if *caller == admin || read_role(env, role, caller) {
+        Ok(())
+    } else {
+        Err(SinkError::Unauthorized)
+    }
+}
@@ -0,0 +1,167 @@
+use soroban_sdk::{
+    token::{StellarAssetClient, TokenClient}, xdr::ScErrorType, Address, Env, Error, InvokeError
+};
+
+use crate::errors::{
+    map_authorize_error, map_burn_error, map_mint_error, publish_invoke_error, publish_sac_error, SinkError,
+};
+use crate::fees::read_treasury;
+use crate::storage_types::{read_ttl_policy, DataKey};
+
+/// Extends the TTL of the CARBON and CarbonSINK SAC instances with the instance TTL policy of this contract.
+/// The SACs only extend their own instances by a few days when they are invoked, so without this
+/// a sink could fail after a quiet period because an asset contract has been archived.
+/// SACs are built into the network, so they have no code entry that needs extending.
+pub fn extend_sac_ttl(env: &Env) {
+    let policy = read_ttl_policy(env);
+    for key in [DataKey::CarbonID, DataKey::CarbonSinkID] {
+        let sac_id: Address = env.storage().instance().get(&key).unwrap();
+        env.deployer().extend_ttl_for_contract_instance(
+            sac_id, policy.instance_threshold, policy.instance_extend_amount
+        );
+    }
+}
+
+/// Burns `amount` of CARBON from `funder`, translating SAC errors to a `SinkError`.
+/// The caller is responsible for requiring the funder's authorization.
+pub fn burn_carbon(env: &Env, funder: &Address, amount: i128) -> Result<(), SinkError> {
+    let carbon_id = env.storage().instance().get(&DataKey::CarbonID).unwrap();
+    let carbon_client = TokenClient::new(env, &carbon_id);
+    handle_carbon_result(env, "burn_carbon", carbon_client.try_burn(funder, &amount))
+}
+
+/// Burns `amount` of CARBON from `funder`, spending the allowance that `funder` has granted
+/// to this contract, and translating SAC errors to a `SinkError`.
+pub fn burn_carbon_from(env: &Env, funder: &Address, amount: i128) -> Result<(), SinkError> {
+    let carbon_id = env.storage().instance().get(&DataKey::CarbonID).unwrap();
+    let carbon_client = TokenClient::new(env, &carbon_id);
+    handle_carbon_result(
+        env, "burn_carbon", carbon_client.try_burn_from(&env.current_contract_address(), funder, &amount)
+    )
+}
+
+/// Transfers the protocol `fee` in CARBON from `funder` to the treasury, translating SAC errors to a `SinkError`.
+/// If `from_allowance` is true, the allowance that `funder` has granted to this contract is spent instead.
+/// The caller is responsible for requiring the funder's authorization.
+pub fn transfer_fee(env: &Env, funder: &Address, fee: i128, from_allowance: bool) -> Result<(), SinkError> {
+    let carbon_id = env.storage().instance().get(&DataKey::CarbonID).unwrap();
+    let carbon_client = TokenClient::new(env, &carbon_id);
+    let treasury = read_treasury(env);
+    let result = if from_allowance {
+        carbon_client.try_transfer_from(&env.current_contract_address(), funder, &treasury, &fee)
+    } else {
+        carbon_client.try_transfer(funder, &treasury, &fee)
+    };
+    handle_carbon_result(env, "transfer_fee", result)
+}
+
+/// The SAC clients return every failure as an `Error`, including failures that are not errors of the SAC
+/// itself, e.g. a failed authorization. Moves those to an `InvokeError`, so that only the SAC's own
+/// error codes are mapped to a specific `SinkError`.
+fn split_invoke_error<T, C>(
+    result: Result<Result<T, C>, Result<Error, InvokeError>>
+) -> Result<Result<T, C>, Result<Error, InvokeError>> {
+    match result {
+        Err(Ok(err)) if !err.is_type(ScErrorType::Contract) => Err(Err(err.into())),
+        result => result,
+    }
+}
+
+fn handle_carbon_result<T, C>(
+    env: &Env, fn_name: &str, result: Result<Result<T, C>, Result<Error, InvokeError>>
+) -> Result<(), SinkError> {
+    match split_invoke_error(result) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => {
+            publish_sac_error(env, "carbon_sac_error", err.get_code());
+            Err(map_burn_error(err))
+        },
+        Err(Err(invoke_err)) => {
+            publish_invoke_error(env, fn_name, invoke_err);
+            Err(SinkError::SacInvocationFailed)
+        }
+    }
+}
+
+/// Mints `amount` of CarbonSINK to `recipient` and locks the balance onto the recipient's account,
+/// translating SAC errors to a `SinkError`.
+pub fn mint_carbonsink(env: &Env, recipient: &Address, amount: i128) -> Result<(), SinkError> {
+    let carbonsink_id = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
+    let carbonsink_client = StellarAssetClient::new(env, &carbonsink_id);
+    match split_invoke_error(carbonsink_client.try_set_authorized(recipient, &true)) {
+        Ok(_) => {}
+        Err(Ok(err)) => {
+            publish_sac_error(env, "csink_sac_error", err.get_code());
+            return Err(map_authorize_error(err));
+        },
+        Err(Err(invoke_err)) => {
+            publish_invoke_error(env, "set_authorized", invoke_err);
+            return Err(map_carbonsink_invoke_error(env, &carbonsink_client));
+        }
+    };
+    let mint_res = match split_invoke_error(carbonsink_client.try_mint(recipient, &amount)) {
+        Ok(_) => Ok(()),
+        Err(Ok(err)) => {
+            publish_sac_error(env, "csink_sac_error", err.get_code());
+            Err(map_mint_error(err))
+        },
+        Err(Err(invoke_err)) => {
+            publish_invoke_error(env, "mint_csink", invoke_err);
+            Err(map_carbonsink_invoke_error(env, &carbonsink_client))
+        }
+    };
+    // lock the balance again, also after a failed mint in case the caller carries on
+    carbonsink_client.set_authorized(recipient, &false);
+    mint_res
+}
+
+/// The CarbonSINK SAC requires the authorization of its admin for `set_authorized` and `mint`, which fails
+/// without a contract error if this contract is not the admin. Tells that case apart by reading the SAC admin.
+fn map_carbonsink_invoke_error(env: &Env, carbonsink_client: &StellarAssetClient) -> SinkError {
+    match carbonsink_client.try_admin() {
+        Ok(Ok(sac_admin)) if sac_admin != env.current_contract_address() => SinkError::NotCarbonSinkAdmin,
+        _ => SinkError::SacInvocationFailed,
+    }
+}
+
+/// Predicts whether `burn_carbon` of `amount` from `funder` would succeed, without burning anything.
+pub fn check_burn(env: &Env, funder: &Address, amount: i128) -> Result<(), SinkError> {
+    let carbon_id = env.storage().instance().get(&DataKey::CarbonID).unwrap();
+    let balance = read_sac_result(TokenClient::new(env, &carbon_id).try_balance(funder), map_burn_error)?;
+    let carbon_client = StellarAssetClient::new(env, &carbon_id);
+    if !read_sac_result(carbon_client.try_authorized(funder), map_burn_error)? {
+        return Err(SinkError::FunderBalanceDeauthorized);
+    }
+    if balance < amount {
+        return Err(SinkError::InsufficientBalance);
+    }
+    Ok(())
+}
+
+/// Predicts whether `mint_carbonsink` of `amount` to `recipient` would succeed, without minting anything.
+/// The SAC does not expose trustline limits, so only the maximum balance of any trustline is checked.
+pub fn check_mint(env: &Env, recipient: &Address, amount: i128) -> Result<(), SinkError> {
+    let carbonsink_id = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
+    let carbonsink_client = StellarAssetClient::new(env, &carbonsink_id);
+    let sac_admin = read_sac_result(carbonsink_client.try_admin(), map_authorize_error)?;
+    if sac_admin != env.current_contract_address() {
+        return Err(SinkError::NotCarbonSinkAdmin);
+    }
+    let carbonsink_token_client = TokenClient::new(env, &carbonsink_id);
+    let balance = read_sac_result(carbonsink_token_client.try_balance(recipient), map_authorize_error)?;
+    if balance + amount > i64::MAX as i128 {
+        return Err(SinkError::TrustlineLimitReached);
+    }
+    Ok(())
+}
+
+fn read_sac_result<T, C>(
+    result: Result<Result<T, C>, Result<Error, InvokeError>>,
+    map_error: fn(Error) -> SinkError,
+) -> Result<T, SinkError> {
+    match split_invoke_error(result) {
+        Ok(Ok(value)) => Ok(value),
+        Err(Ok(err)) => Err(map_error(err)),
+        _ => Err(SinkError::SacInvocationFailed),
+    }
+}
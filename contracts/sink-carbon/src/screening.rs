@@ -0,0 +1,91 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::errors::SinkError;
+use crate::retirements::MAX_PAGE_SIZE;
+use crate::storage_types::{extend_persistent_ttl, read_set_count, read_set_members, write_set_member, DataKey};
+
+/// A list of addresses that the admin maintains for compliance screening of funders and recipients.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum ScreeningList {
+    /// Addresses that can neither fund nor receive a sink.
+    Denylist = 1,
+    /// The only addresses that can fund or receive a sink while allowlist-only mode is enabled.
+    Allowlist = 2,
+}
+
+/// Checks whether `account` is on `list`, and extends the TTL of its entry if it is.
+pub fn read_listed(env: &Env, list: ScreeningList, account: &Address) -> bool {
+    let key = DataKey::Listed(list, account.clone());
+    let listed = env.storage().persistent().has(&key);
+    if listed {
+        extend_persistent_ttl(env, &key);
+    }
+    listed
+}
+
+/// Adds `account` to `list` or removes it. Each list is an enumerable set, see `write_set_member`.
+pub fn write_listed(env: &Env, list: ScreeningList, account: &Address, listed: bool) {
+    write_set_member(
+        env,
+        &DataKey::ListCount(list),
+        |position| DataKey::ListEntry(list, position),
+        |account: &Address| DataKey::Listed(list, account.clone()),
+        account,
+        listed,
+    );
+}
+
+/// Returns a page of the addresses on `list`, and extends the TTL of their entries.
+/// Removals move the last address of the list, so a listing can skip or repeat an address
+/// if the list changes between pages.
+pub fn read_list(env: &Env, list: ScreeningList, cursor: u32, limit: u32) -> Vec<Address> {
+    read_set_members(
+        env,
+        &DataKey::ListCount(list),
+        |position| DataKey::ListEntry(list, position),
+        |account: &Address| DataKey::Listed(list, account.clone()),
+        cursor,
+        limit.min(MAX_PAGE_SIZE),
+    )
+}
+
+/// Reads the number of addresses on `list`.
+pub fn read_list_count(env: &Env, list: ScreeningList) -> u32 {
+    read_set_count(env, &DataKey::ListCount(list))
+}
+
+pub fn read_allowlist_only(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::AllowlistOnly).unwrap_or(false)
+}
+
+pub fn write_allowlist_only(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::AllowlistOnly, &enabled);
+}
+
+/// Checks that `funder` is not blocked by the denylist or by allowlist-only mode.
+pub fn check_funder(env: &Env, funder: &Address) -> Result<(), SinkError> {
+    check_not_blocked(env, funder)
+}
+
+/// Checks that `recipient` is neither this contract nor one of the asset contracts, which would make
+/// the CarbonSINK unrecoverable, and that it is not blocked by the denylist or by allowlist-only mode.
+pub fn check_recipient(env: &Env, recipient: &Address) -> Result<(), SinkError> {
+    let carbon_id: Address = env.storage().instance().get(&DataKey::CarbonID).unwrap();
+    let carbonsink_id: Address = env.storage().instance().get(&DataKey::CarbonSinkID).unwrap();
+    if *recipient == env.current_contract_address() || *recipient == carbon_id || *recipient == carbonsink_id {
+        return Err(SinkError::InvalidRecipient);
+    }
+    check_not_blocked(env, recipient)
+}
+
+fn check_not_blocked(env: &Env, account: &Address) -> Result<(), SinkError> {
+    if read_listed(env, ScreeningList::Denylist, account) {
+        return Err(SinkError::AddressBlocked);
+    }
+    if read_allowlist_only(env) && !read_listed(env, ScreeningList::Allowlist, account) {
+        return Err(SinkError::AddressBlocked);
+    }
+    Ok(())
+}
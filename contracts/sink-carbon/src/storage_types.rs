@@ -1,8 +1,17 @@
-use soroban_sdk::{contracttype, Env};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec};
+
+use crate::errors::SinkError;
+use crate::roles::Role;
+use crate::screening::ScreeningList;
 
 pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+// the default TTL policy, which applies until the admin calls `set_ttl_policy`
 pub(crate) const INSTANCE_EXTEND_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 pub(crate) const INSTANCE_TTL_THRESHOLD: u32 = INSTANCE_EXTEND_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_EXTEND_AMOUNT: u32 = 180 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_TTL_THRESHOLD: u32 = PERSISTENT_EXTEND_AMOUNT - 30 * DAY_IN_LEDGERS;
+/// The minimum extension amount of a TTL policy, so that the contract cannot be configured to archive itself.
+pub(crate) const MIN_TTL_EXTEND_AMOUNT: u32 = DAY_IN_LEDGERS;
 
 #[derive(Clone)]
 #[contracttype]
@@ -12,13 +21,297 @@ pub enum DataKey {
     CarbonSinkID,
     IsActive,
     SinkMinimum,
-    ContractSuccessor
+    ContractSuccessor,
+    RetirementCount,
+    Retirement(u64),
+    RecipientRetirementCount(Address),
+    RecipientRetirement(Address, u32),
+    FunderRetirementCount(Address),
+    FunderRetirement(Address, u32),
+    TotalSunk,
+    UniqueRecipients,
+    RecipientTotal(Address),
+    FunderTotal(Address),
+    ProjectTotal(Symbol),
+    Project(Symbol),
+    ProjectList,
+    ProjectInventory(Symbol),
+    Operator(Address, Address),
+    OperatorCount,
+    OperatorEntry(u32),
+    FeeBps,
+    Treasury,
+    QuantizationUnit,
+    RoundingPolicy,
+    PendingAdmin,
+    Role(Role, Address),
+    RoleCount(Role),
+    RoleEntry(Role, u32),
+    SchemaVersion,
+    TimelockDelay,
+    ScheduledOpCount,
+    ScheduledOp(u64),
+    ForwardSinks,
+    TtlPolicy,
+    SinkMaximum,
+    RateLimit,
+    FunderUsage(Address),
+    Listed(ScreeningList, Address),
+    ListCount(ScreeningList),
+    ListEntry(ScreeningList, u32),
+    AllowlistOnly,
+}
+
+/// A persistent record of a single successful sink operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Retirement {
+    pub id: u64,
+    pub funder: Address,
+    pub recipient: Address,
+    pub amount: i64,
+    pub project_id: Symbol,
+    pub memo_text: String,
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+/// Descriptive metadata of an impact project, as provided by the admin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProjectInfo {
+    pub registry: String,
+    pub methodology: String,
+    pub vintage: u32,
+    pub country: String,
+    pub metadata_uri: String,
+}
+
+/// An entry in the project registry. Only listed projects can be sunk against.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Project {
+    pub project_id: Symbol,
+    pub info: ProjectInfo,
+    pub is_listed: bool,
+}
+
+/// The unit to which sunk amounts are quantized. The discriminant is the size of the unit in
+/// CARBON decigrams, the smallest unit of the 7-decimal CARBON asset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum QuantizationUnit {
+    Gram = 10,
+    Kilogram = 10_000,
+    Tonne = 10_000_000,
+}
+
+/// How an amount that is not a whole multiple of the quantization unit is handled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum RoundingPolicy {
+    /// Round down, leaving the remainder with the funder.
+    Truncate = 0,
+    /// Reject the amount with `AmountNotAligned`.
+    Strict = 1,
+    /// Round to the nearest multiple, with halves rounded up.
+    Nearest = 2,
+}
+
+/// Cumulative figures for all sink operations performed by this contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SinkTotals {
+    pub total_sunk: i128,
+    pub sink_count: u64,
+    pub unique_recipients: u64,
+}
+
+/// The TTL thresholds and extension amounts in ledgers of the storage of this contract.
+/// An entry whose TTL has dropped below the threshold is extended to the extension amount when it is accessed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TtlPolicy {
+    /// Applies to the contract instance and code.
+    pub instance_threshold: u32,
+    pub instance_extend_amount: u32,
+    /// Applies to the persistent entries, such as retirement records and the project registry.
+    pub persistent_threshold: u32,
+    pub persistent_extend_amount: u32,
+}
+
+impl TtlPolicy {
+    /// Checks that both thresholds are at most their extension amount, and that both extension amounts
+    /// are at least one day and within the maximum TTL of the network.
+    pub fn validate(&self, env: &Env) -> Result<(), SinkError> {
+        let max_ttl = env.storage().max_ttl();
+        for (threshold, extend_amount) in [
+            (self.instance_threshold, self.instance_extend_amount),
+            (self.persistent_threshold, self.persistent_extend_amount),
+        ] {
+            if threshold > extend_amount || extend_amount < MIN_TTL_EXTEND_AMOUNT || extend_amount > max_ttl {
+                return Err(SinkError::InvalidTtlPolicy);
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn read_ttl_policy(env: &Env) -> TtlPolicy {
+    env.storage().instance().get(&DataKey::TtlPolicy).unwrap_or(TtlPolicy {
+        instance_threshold: INSTANCE_TTL_THRESHOLD,
+        instance_extend_amount: INSTANCE_EXTEND_AMOUNT,
+        persistent_threshold: PERSISTENT_TTL_THRESHOLD,
+        persistent_extend_amount: PERSISTENT_EXTEND_AMOUNT,
+    })
+}
+
+pub fn write_ttl_policy(env: &Env, policy: &TtlPolicy) {
+    env.storage().instance().set(&DataKey::TtlPolicy, policy);
 }
 
 pub fn extend_instance_ttl(env: &Env) {
-    env.storage().instance().extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_EXTEND_AMOUNT);
+    let policy = read_ttl_policy(env);
+    env.storage().instance().extend_ttl(policy.instance_threshold, policy.instance_extend_amount);
+}
+
+pub fn extend_persistent_ttl<K>(env: &Env, key: &K)
+where
+    K: IntoVal<Env, Val>,
+{
+    let policy = read_ttl_policy(env);
+    env.storage().persistent().extend_ttl(key, policy.persistent_threshold, policy.persistent_extend_amount);
 }
 
 pub fn set_is_active(env: &Env, val: bool) {
     env.storage().instance().set(&DataKey::IsActive, &val);
 }
+
+pub fn read_operator(env: &Env, funder: &Address, operator: &Address) -> bool {
+    let key = DataKey::Operator(funder.clone(), operator.clone());
+    let approved = env.storage().persistent().has(&key);
+    if approved {
+        extend_persistent_ttl(env, &key);
+    }
+    approved
+}
+
+/// Approves or revokes `operator` for `funder`. Approvals are kept in an enumerable set,
+/// so that `extend_ttl` can reach them.
+pub fn write_operator(env: &Env, funder: &Address, operator: &Address, approved: bool) {
+    write_set_member(
+        env,
+        &DataKey::OperatorCount,
+        DataKey::OperatorEntry,
+        operator_key,
+        &(funder.clone(), operator.clone()),
+        approved,
+    );
+}
+
+/// Extends the TTL of up to `limit` operator approvals, starting at position `cursor`.
+pub fn extend_operators_ttl(env: &Env, cursor: u32, limit: u32) {
+    read_set_members(env, &DataKey::OperatorCount, DataKey::OperatorEntry, operator_key, cursor, limit);
+}
+
+fn operator_key(approval: &(Address, Address)) -> DataKey {
+    DataKey::Operator(approval.0.clone(), approval.1.clone())
+}
+
+/// Reads the number of members of an enumerable set, see `write_set_member`.
+pub fn read_set_count(env: &Env, count_key: &DataKey) -> u32 {
+    env.storage().persistent().get(count_key).unwrap_or(0)
+}
+
+/// Adds `member` to an enumerable set in persistent storage or removes it. Each set consists of a count entry,
+/// one entry per position that holds the member, and one entry per member that holds its position,
+/// so that a member can be removed by moving the last member of the set into its position.
+pub fn write_set_member<T, P, M>(
+    env: &Env, count_key: &DataKey, position_key: P, member_key: M, member: &T, is_member: bool
+)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    P: Fn(u32) -> DataKey,
+    M: Fn(&T) -> DataKey,
+{
+    let storage = env.storage().persistent();
+    let count = read_set_count(env, count_key);
+    match (storage.get::<_, u32>(&member_key(member)), is_member) {
+        (None, true) => {
+            write_set_entry(env, &position_key, &member_key, count, member);
+            write_set_count(env, count_key, count + 1);
+        }
+        (Some(position), false) => {
+            let last = count - 1;
+            if position != last {
+                let last_member: T = storage.get(&position_key(last)).unwrap();
+                write_set_entry(env, &position_key, &member_key, position, &last_member);
+            }
+            storage.remove(&position_key(last));
+            storage.remove(&member_key(member));
+            write_set_count(env, count_key, last);
+        }
+        _ => {}
+    }
+}
+
+/// Returns up to `limit` members of an enumerable set, starting at position `cursor`, and extends the TTL
+/// of their entries and of the count entry. Removals move the last member of the set,
+/// so a listing can skip or repeat a member if the set changes between pages.
+pub fn read_set_members<T, P, M>(
+    env: &Env, count_key: &DataKey, position_key: P, member_key: M, cursor: u32, limit: u32
+) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    P: Fn(u32) -> DataKey,
+    M: Fn(&T) -> DataKey,
+{
+    let end = cursor.saturating_add(limit).min(read_set_count(env, count_key));
+
+    let mut members = Vec::new(env);
+    for position in cursor..end {
+        let entry_key = position_key(position);
+        let member: T = env.storage().persistent().get(&entry_key).unwrap();
+        extend_persistent_ttl(env, &entry_key);
+        extend_persistent_ttl(env, &member_key(&member));
+        members.push_back(member);
+    }
+    if cursor < end {
+        extend_persistent_ttl(env, count_key);
+    }
+    members
+}
+
+fn write_set_count(env: &Env, count_key: &DataKey, count: u32) {
+    env.storage().persistent().set(count_key, &count);
+    extend_persistent_ttl(env, count_key);
+}
+
+fn write_set_entry<T, P, M>(env: &Env, position_key: &P, member_key: &M, position: u32, member: &T)
+where
+    T: IntoVal<Env, Val>,
+    P: Fn(u32) -> DataKey,
+    M: Fn(&T) -> DataKey,
+{
+    let entry_key = position_key(position);
+    env.storage().persistent().set(&entry_key, member);
+    extend_persistent_ttl(env, &entry_key);
+    let key = member_key(member);
+    env.storage().persistent().set(&key, &position);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn read_quantization_unit(env: &Env) -> QuantizationUnit {
+    env.storage().instance().get(&DataKey::QuantizationUnit).unwrap_or(QuantizationUnit::Kilogram)
+}
+
+pub fn read_rounding_policy(env: &Env) -> RoundingPolicy {
+    env.storage().instance().get(&DataKey::RoundingPolicy).unwrap_or(RoundingPolicy::Truncate)
+}
+
+pub fn write_quantization(env: &Env, unit: QuantizationUnit, rounding: RoundingPolicy) {
+    env.storage().instance().set(&DataKey::QuantizationUnit, &unit);
+    env.storage().instance().set(&DataKey::RoundingPolicy, &rounding);
+}
@@ -0,0 +1,71 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::contract::SinkContractClient;
+use crate::errors::SinkError;
+use crate::storage_types::DataKey;
+
+/// The maximum number of successor contracts that a forwarded sink follows to find an active contract.
+pub(crate) const MAX_FORWARD_HOPS: u32 = 5;
+/// The maximum number of successor contracts that `get_latest_contract` follows.
+/// Every hop costs two cross-contract calls.
+pub(crate) const MAX_RESOLVE_HOPS: u32 = 10;
+
+/// Reads the contract successor, or `None` if no successor other than this contract is set.
+pub fn read_successor(env: &Env) -> Option<Address> {
+    let successor: Address = env.storage().instance().get(&DataKey::ContractSuccessor)?;
+    if successor == env.current_contract_address() {
+        None
+    } else {
+        Some(successor)
+    }
+}
+
+pub fn read_forwarding(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::ForwardSinks).unwrap_or(false)
+}
+
+pub fn write_forwarding(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::ForwardSinks, &enabled);
+}
+
+/// The error for a sink on this contract while it is deactivated: `ContractSuperseded` if a successor is set,
+/// so that clients can tell an upgrade from a pause, and `ContractDeactivated` otherwise.
+pub fn inactive_error(env: &Env) -> SinkError {
+    if read_successor(env).is_some() {
+        SinkError::ContractSuperseded
+    } else {
+        SinkError::ContractDeactivated
+    }
+}
+
+/// Follows the successor chain of this contract for at most `max_hops` contracts, and returns the first
+/// active successor with the number of hops to it. Every successor must implement the `is_active` and
+/// `get_contract_successor` functions of the SinkContract.
+pub fn resolve_active_successor(env: &Env, max_hops: u32) -> Result<(Address, u32), SinkError> {
+    let mut visited = Vec::from_array(env, [env.current_contract_address()]);
+    let mut next = read_successor(env).ok_or(SinkError::ContractDeactivated)?;
+    for hops in 1..=max_hops {
+        // a contract on the call stack cannot be invoked again, so check for cycles before the call
+        if visited.contains(&next) {
+            return Err(SinkError::SuccessorCycle);
+        }
+        let client = SinkContractClient::new(env, &next);
+        match client.try_is_active() {
+            Ok(Ok(true)) => return Ok((next, hops)),
+            Ok(Ok(false)) => {}
+            // the successor is not a SinkContract
+            _ => return Err(SinkError::SuccessorDeadEnd),
+        }
+        let successor = match client.try_get_contract_successor() {
+            Ok(Ok(successor)) => successor,
+            _ => return Err(SinkError::SuccessorDeadEnd),
+        };
+        if successor == next {
+            // the chain ends at a deactivated contract
+            return Err(SinkError::ContractDeactivated);
+        }
+        visited.push_back(next);
+        next = successor;
+    }
+    Err(SinkError::SuccessorChainTooLong)
+}
@@ -1,10 +1,14 @@
 use soroban_sdk::{
     testutils::{Address as _, IssuerFlags, Ledger, MockAuth, MockAuthInvoke, StellarAssetContract},
     token::StellarAssetClient,
-    Address, Env, IntoVal,
+    Address, Env, IntoVal, String, Symbol,
 };
 
 use crate::contract::{SinkContract, SinkContractClient};
+use crate::storage_types::ProjectInfo;
+
+/// Projects that are listed in the registry of every test setup.
+pub const TEST_PROJECTS: [&str; 4] = ["VCS1360", "OFP1234567890", "SOMEPROJECT", "max_impact"];
 
 pub struct Setup<'a> {
     pub env: Env,
@@ -66,11 +70,97 @@ pub fn set_up_contracts_and_funder_inner<'a>(funder: Address, funder_balance: i1
 
     let sink_client = SinkContractClient::new(&env, &contract_id);
 
+    // list the test projects in the project registry, with ample inventory
+    for project_id in TEST_PROJECTS {
+        let project_id = Symbol::new(&env, project_id);
+        add_project_as_admin(&sink_client, &carbonsink_issuer, &project_id);
+        add_project_inventory_as_admin(&sink_client, &carbonsink_issuer, &project_id, i64::MAX);
+    }
+
     Setup {
         env, funder, carbon_sac, carbonsink_issuer, carbonsink_sac, contract_id, sink_client,
     }
 }
 
+/// Deploys another SinkContract with the same admin and assets as `setup`, and lists the test projects.
+/// The CarbonSINK SAC admin is not changed, see `supersede_contract`.
+pub fn deploy_sink_contract<'a>(setup: &Setup) -> SinkContractClient<'a> {
+    let env = &setup.env;
+    let admin = &setup.carbonsink_issuer;
+    let contract_id = env.register(
+        SinkContract,
+        (admin, &setup.carbon_sac.address(), &setup.carbonsink_sac.address())
+    );
+    let sink_client = SinkContractClient::new(env, &contract_id);
+    for project_id in TEST_PROJECTS {
+        let project_id = Symbol::new(env, project_id);
+        add_project_as_admin(&sink_client, admin, &project_id);
+        add_project_inventory_as_admin(&sink_client, admin, &project_id, i64::MAX);
+    }
+    sink_client
+}
+
+/// Supersedes the contract of `setup` by `successor` as in the upgrade process: resets the CarbonSINK SAC admin,
+/// which deactivates the contract, makes `successor` the CarbonSINK SAC admin, and sets it as the contract successor.
+pub fn supersede_contract(setup: &Setup, successor: &Address) {
+    let admin = &setup.carbonsink_issuer;
+    setup.sink_client.mock_all_auths().reset_admin(admin);
+    StellarAssetClient::new(&setup.env, &setup.carbonsink_sac.address())
+        .mock_all_auths()
+        .set_admin(successor);
+    setup.sink_client.mock_all_auths().set_contract_successor(admin, successor);
+}
+
+/// Deactivates the contract of `sink_client` and sets `successor` as its contract successor.
+pub fn deactivate_with_successor(setup: &Setup, sink_client: &SinkContractClient, successor: &Address) {
+    let admin = &setup.carbonsink_issuer;
+    sink_client.mock_all_auths().deactivate(admin);
+    sink_client.mock_all_auths().set_contract_successor(admin, successor);
+}
+
+pub fn test_project_info(env: &Env) -> ProjectInfo {
+    ProjectInfo {
+        registry: String::from_str(env, "Verra"),
+        methodology: String::from_str(env, "VM0007"),
+        vintage: 2023,
+        country: String::from_str(env, "KE"),
+        metadata_uri: String::from_str(env, "https://registry.verra.org/app/projectDetail/VCS/1360"),
+    }
+}
+
+pub fn add_project_as_admin(sink_client: &SinkContractClient, admin: &Address, project_id: &Symbol) {
+    let env = &sink_client.env;
+    let info = test_project_info(env);
+    sink_client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &sink_client.address,
+                fn_name: "add_project",
+                args: (project_id, info.clone()).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .add_project(project_id, &info);
+}
+
+pub fn add_project_inventory_as_admin(
+    sink_client: &SinkContractClient, admin: &Address, project_id: &Symbol, amount: i64
+) {
+    let env = &sink_client.env;
+    sink_client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &sink_client.address,
+                fn_name: "add_project_inventory",
+                args: (project_id, amount).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .add_project_inventory(project_id, &amount);
+}
+
 pub fn create_env_with_ttl_settings(max_entry_ttl: u32) -> Env {
     let env = Env::default();
     env.ledger().with_mut(|li| {
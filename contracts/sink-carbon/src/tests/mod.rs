@@ -1,7 +1,23 @@
 #![cfg(test)]
 
+pub mod test_admin_transfer;
+pub mod test_check_sink;
+pub mod test_fees;
+pub mod test_forwarding;
+pub mod test_latest_contract;
+pub mod test_limits;
+pub mod test_projects;
+pub mod test_quantization;
+pub mod test_retirements;
+pub mod test_roles;
+pub mod test_sac_errors;
+pub mod test_screening;
 pub mod test_sink_carbon;
+pub mod test_sink_carbon_batch;
+pub mod test_sink_carbon_from;
 pub mod test_supporting;
+pub mod test_timelock;
 pub mod test_ttl_extension;
+pub mod test_upgrade;
 pub mod fixtures;
 pub mod utils;
@@ -0,0 +1,160 @@
+use soroban_sdk::{Address, IntoVal};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::StellarAssetClient;
+
+use crate::errors::SinkError;
+use crate::events::{AdminChangedEvent, AdminProposalChangedEvent};
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+
+fn propose_admin_as_admin(setup: &Setup, new_admin: &Address) {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "propose_admin",
+                args: (new_admin,).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .propose_admin(new_admin);
+}
+
+fn accept_admin_as(setup: &Setup, new_admin: &Address) -> Address {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: new_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "accept_admin",
+                args: ().into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .accept_admin()
+}
+
+#[test]
+fn test_get_admin() {
+    let setup = set_up_contracts_and_funder(0, None);
+    assert_eq!(setup.sink_client.get_admin(), setup.carbonsink_issuer);
+    assert_eq!(setup.sink_client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_propose_and_accept_admin() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let old_admin = setup.carbonsink_issuer.clone();
+    let new_admin = Address::generate(env);
+
+    propose_admin_as_admin(&setup, &new_admin);
+    let event: AdminProposalChangedEvent = last_admin_event(env, "propose_admin");
+    assert_eq!(
+        event,
+        AdminProposalChangedEvent {
+            admin: old_admin.clone(),
+            old_pending_admin: client.address.clone(),
+            new_pending_admin: new_admin.clone(),
+        }
+    );
+    // the admin only changes upon acceptance
+    assert_eq!(client.get_admin(), old_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    assert_eq!(accept_admin_as(&setup, &new_admin), new_admin);
+    let event: AdminChangedEvent = last_admin_event(env, "accept_admin");
+    assert_eq!(event, AdminChangedEvent { old_admin, new_admin: new_admin.clone() });
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // the new admin can use admin functions
+    client
+        .mock_auths(&[MockAuth {
+            address: &new_admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "deactivate",
+                args: (&new_admin,).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .deactivate(&new_admin);
+    assert!(!client.is_active());
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_accept_admin_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let new_admin = Address::generate(&setup.env);
+    propose_admin_as_admin(&setup, &new_admin);
+
+    // only the pending admin can accept
+    accept_admin_as(&setup, &Address::generate(&setup.env));
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_propose_admin_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let new_admin = Address::generate(&setup.env);
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.propose_admin(&new_admin);
+}
+
+#[test]
+fn test_no_pending_admin() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = setup.sink_client.mock_all_auths();
+    assert_eq!(client.try_accept_admin().unwrap_err().unwrap(), SinkError::NoPendingAdmin);
+    assert_eq!(client.try_cancel_admin_proposal().unwrap_err().unwrap(), SinkError::NoPendingAdmin);
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let new_admin = Address::generate(env);
+    propose_admin_as_admin(&setup, &new_admin);
+
+    client.mock_all_auths().cancel_admin_proposal();
+    let event: AdminProposalChangedEvent = last_admin_event(env, "cancel_admin_proposal");
+    assert_eq!(
+        event,
+        AdminProposalChangedEvent {
+            admin: setup.carbonsink_issuer.clone(),
+            old_pending_admin: new_admin,
+            new_pending_admin: client.address.clone(),
+        }
+    );
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.mock_all_auths().try_accept_admin().unwrap_err().unwrap(), SinkError::NoPendingAdmin);
+}
+
+#[test]
+fn test_reset_admin_after_transfer() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let carbonsink_client = StellarAssetClient::new(env, &setup.carbonsink_sac.address());
+    let new_admin = Address::generate(env);
+    propose_admin_as_admin(&setup, &new_admin);
+    accept_admin_as(&setup, &new_admin);
+
+    // a pending proposal is cancelled by the reset
+    let next_admin = Address::generate(env);
+    client.mock_all_auths().propose_admin(&next_admin);
+
+    // the CarbonSINK SAC admin is handed to the new admin rather than the issuer
+    assert_eq!(client.mock_all_auths().reset_admin(&new_admin), new_admin);
+    assert_eq!(carbonsink_client.admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(!client.is_active());
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{vec, Address, Symbol};
+use soroban_sdk::testutils::Address as _;
+
+use crate::contract::SinkCheck;
+use crate::errors::SinkError;
+use crate::tests::fixtures::set_up_contracts_and_funder;
+use crate::tests::utils::{create_account_entry, create_trustline, sink_carbon_with_auth, SinkTestData};
+
+#[test]
+fn test_check_sink_happy() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let recipient = Address::generate(env);
+
+    // check a sink of 0.1234567 CARBON
+    let check = setup.sink_client.check_sink(
+        &setup.funder, &recipient, &1_234_567, &Symbol::new(env, "VCS1360")
+    );
+    assert_eq!(
+        check,
+        SinkCheck { amount: 1_230_000, fee: 0, remainder: 4_567, errors: vec![env] }
+    );
+
+    // the check does not sink anything, nor does it require authorization
+    assert!(env.auths().is_empty());
+    assert_eq!(setup.sink_client.get_totals().sink_count, 0);
+
+    // the actual sink succeeds
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &recipient,
+        amount: 1_234_567_i64,
+        project_id: "VCS1360",
+        memo_text: "123 kg 🌳🌴",
+    };
+    assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+}
+
+#[test]
+fn test_check_sink_collects_errors() {
+    let setup = set_up_contracts_and_funder(500, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    client.mock_all_auths().deactivate(&setup.carbonsink_issuer);
+
+    // check a sink of 0.0999 CARBON against an unknown project
+    let check = client.check_sink(
+        &setup.funder, &Address::generate(env), &999_999, &Symbol::new(env, "UNKNOWN")
+    );
+    assert_eq!(check.amount, 990_000);
+    assert_eq!(check.remainder, 9_999);
+    assert_eq!(
+        check.errors,
+        vec![
+            env,
+            SinkError::ContractDeactivated as u32,
+            SinkError::AmountTooLow as u32,
+            SinkError::UnknownProject as u32,
+            SinkError::InsufficientBalance as u32,
+        ]
+    );
+}
+
+#[test]
+fn test_check_sink_agrees_with_sink() {
+    let setup = set_up_contracts_and_funder(500, None);
+    let env = &setup.env;
+
+    // the funder has too little CARBON to sink 0.1 CARBON
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let check = setup.sink_client.check_sink(
+        &setup.funder, &setup.funder, &1_000_000, &Symbol::new(env, "VCS1360")
+    );
+    assert_eq!(check.errors, vec![env, SinkError::InsufficientBalance as u32]);
+    assert_eq!(sink_carbon_with_auth(&setup, &test_data).unwrap_err(), SinkError::InsufficientBalance);
+}
+
+#[test]
+fn test_check_sink_recipient_trustline() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let project_id = Symbol::new(env, "VCS1360");
+
+    let recipient_pubkey = "GBDCUWVK2SXI6DA6RD23KDBVIWMJSKLDEN3KZBIMX3TH23OJUR4YSQR4";
+    let recipient = Address::from_str(env, recipient_pubkey);
+
+    // the recipient account does not exist
+    let check = client.check_sink(&setup.funder, &recipient, &1_000_000, &project_id);
+    assert_eq!(check.errors, vec![env, SinkError::AccountOrTrustlineMissing as u32]);
+
+    // the recipient account exists, but without a CarbonSINK trustline
+    create_account_entry(env, recipient_pubkey);
+    let check = client.check_sink(&setup.funder, &recipient, &1_000_000, &project_id);
+    assert_eq!(check.errors, vec![env, SinkError::AccountOrTrustlineMissing as u32]);
+
+    // the recipient has a CarbonSINK trustline
+    create_trustline(
+        env, recipient_pubkey, &setup.carbonsink_sac.issuer(), [b'a', b'a', b'a', 0], -1
+    );
+    let check = client.check_sink(&setup.funder, &recipient, &1_000_000, &project_id);
+    assert!(check.errors.is_empty());
+}
+
+#[test]
+fn test_check_sink_funder_trustline_missing() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+
+    let funder = Address::from_str(env, "GA2H3SJYGIUG2DXXUZ7IN3LNO2AIMVWCDCL25PKQHKMC76OWW3HYQHY4");
+    let check = setup.sink_client.check_sink(
+        &funder, &setup.funder, &1_000_000, &Symbol::new(env, "VCS1360")
+    );
+    assert_eq!(check.errors, vec![env, SinkError::AccountOrTrustlineMissing as u32]);
+}
+
+#[test]
+fn test_check_sink_not_carbonsink_admin() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+
+    // hand the CarbonSINK SAC admin back to the issuer, but keep the contract active
+    client.mock_all_auths().reset_admin(&setup.carbonsink_issuer);
+    client.mock_all_auths().activate();
+
+    let check = client.check_sink(
+        &setup.funder, &Address::generate(env), &1_000_000, &Symbol::new(env, "VCS1360")
+    );
+    assert_eq!(check.errors, vec![env, SinkError::NotCarbonSinkAdmin as u32]);
+}
@@ -0,0 +1,218 @@
+use soroban_sdk::{Address, Env, IntoVal, String, Symbol, TryFromVal};
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+
+use crate::contract::{BatchSinkItem, BatchSinkResult};
+use crate::errors::SinkError;
+use crate::events::{FeeChangedEvent, SinkEventData, TreasuryChangedEvent};
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+
+fn set_fee_as_admin(setup: &Setup, fee_bps: u32) -> Result<(), SinkError> {
+    let client = &setup.sink_client;
+    match client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_fee_bps",
+                args: (&setup.carbonsink_issuer, fee_bps).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_fee_bps(&setup.carbonsink_issuer, &fee_bps)
+    {
+        Ok(_) => Ok(()),
+        Err(Ok(sink_err)) => Err(sink_err),
+        Err(Err(invoke_err)) => panic!("InvokeError: {:?}", invoke_err),
+    }
+}
+
+fn set_treasury_as_admin(setup: &Setup, treasury: &Address) {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_treasury",
+                args: (treasury,).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_treasury(treasury);
+}
+
+fn last_sink_event(env: &Env) -> SinkEventData {
+    let (_, _, data) = env.events().all().last().unwrap();
+    SinkEventData::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_fee_defaults() {
+    let setup = set_up_contracts_and_funder(0, None);
+    assert_eq!(setup.sink_client.get_fee_bps(), 0);
+    // the treasury defaults to the admin
+    assert_eq!(setup.sink_client.get_treasury(), setup.carbonsink_issuer);
+}
+
+#[test]
+fn test_set_fee_and_treasury_as_admin() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let admin = &setup.carbonsink_issuer;
+
+    set_fee_as_admin(&setup, 250).unwrap();
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        FeeChangedEvent::try_from_val(env, &data).unwrap(),
+        FeeChangedEvent { admin: admin.clone(), old_fee_bps: 0, new_fee_bps: 250 }
+    );
+    assert_eq!(setup.sink_client.get_fee_bps(), 250);
+
+    let treasury = Address::generate(env);
+    set_treasury_as_admin(&setup, &treasury);
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        TreasuryChangedEvent::try_from_val(env, &data).unwrap(),
+        TreasuryChangedEvent { admin: admin.clone(), old_treasury: admin.clone(), new_treasury: treasury.clone() }
+    );
+    assert_eq!(setup.sink_client.get_treasury(), treasury);
+}
+
+#[test]
+fn test_set_fee_too_high() {
+    let setup = set_up_contracts_and_funder(0, None);
+    // the maximum fee is 10%
+    assert!(set_fee_as_admin(&setup, 1_000).is_ok());
+    assert_eq!(set_fee_as_admin(&setup, 1_001).unwrap_err(), SinkError::FeeTooHigh);
+    assert_eq!(setup.sink_client.get_fee_bps(), 1_000);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_set_fee_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+
+    // it should fail because the call lacks admin auth
+    client
+        .mock_auths(&[MockAuth {
+            address: &Address::generate(&setup.env),
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_fee_bps",
+                args: (admin, 100_u32).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_fee_bps(admin, &100);
+}
+
+#[test]
+fn test_sink_carbon_with_fee() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let treasury = Address::generate(env);
+    set_fee_as_admin(&setup, 250).unwrap();
+    set_treasury_as_admin(&setup, &treasury);
+
+    // have the funder sink 0.1234567 CARBON with a fee of 2.5%
+    let amount = 1_234_567_i64;
+    let project_id = Symbol::new(env, "VCS1360");
+    let memo_text = String::from_str(env, "123 kg 🌳🌴");
+    let receipt = setup.sink_client
+        .mock_all_auths()
+        .sink_carbon(&setup.funder, &setup.funder, &amount, &project_id, &memo_text);
+    assert_eq!(receipt.amount, 1_230_000);
+    assert_eq!(receipt.fee, 30_750);
+
+    // the fee and the net amount are reported in the sink event
+    let event = last_sink_event(env);
+    assert_eq!(event.amount, 1_230_000);
+    assert_eq!(event.fee, 30_750);
+    assert_eq!(setup.sink_client.get_totals().total_sunk, 1_230_000);
+
+    // the fee is charged on top of the quantized amount
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000 - 1_230_000 - 30_750);
+    assert_eq!(carbon_client.balance(&treasury), 30_750);
+    assert_eq!(carbonsink_client.balance(&setup.funder), 1_230_000);
+}
+
+#[test]
+fn test_sink_carbon_with_fee_insufficient_balance() {
+    let setup = set_up_contracts_and_funder(1_000_000, None);
+    let env = &setup.env;
+    set_fee_as_admin(&setup, 100).unwrap();
+
+    // the funder can pay the amount, but not the fee on top of it
+    let sink_res = setup.sink_client.mock_all_auths().try_sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::InsufficientBalance);
+
+    // and the pre-flight check agrees
+    let check = setup.sink_client.check_sink(
+        &setup.funder, &setup.funder, &1_000_000, &Symbol::new(env, "VCS1360")
+    );
+    assert_eq!(check.fee, 10_000);
+    assert_eq!(check.errors.get(0), Some(SinkError::InsufficientBalance as u32));
+}
+
+#[test]
+fn test_sink_carbon_from_with_fee() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let operator = Address::generate(env);
+    set_fee_as_admin(&setup, 1_000).unwrap();
+
+    // the allowance covers both the amount and the fee
+    client.set_operator(&setup.funder, &operator, &true);
+    TokenClient::new(env, &setup.carbon_sac.address())
+        .mock_all_auths()
+        .approve(&setup.funder, &setup.contract_id, &1_100_000, &1_000);
+    client.sink_carbon_from(
+        &operator, &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 8_900_000);
+    assert_eq!(carbon_client.balance(&setup.carbonsink_issuer), 100_000);
+    assert_eq!(carbon_client.allowance(&setup.funder, &setup.contract_id), 0);
+}
+
+#[test]
+fn test_sink_carbon_batch_with_fee() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let treasury = Address::generate(env);
+    set_fee_as_admin(&setup, 500).unwrap();
+    set_treasury_as_admin(&setup, &treasury);
+
+    let item = BatchSinkItem {
+        recipient: Address::generate(env),
+        amount: 2_000_000,
+        project_id: Symbol::new(env, "VCS1360"),
+        memo_text: String::from_str(env, ""),
+    };
+    let results = setup.sink_client
+        .mock_all_auths()
+        .sink_carbon_batch(&setup.funder, &soroban_sdk::vec![env, item.clone(), item], &true);
+    match results.get(1) {
+        Some(BatchSinkResult::Sunk(receipt)) => {
+            assert_eq!(receipt.retirement_id, 2);
+            assert_eq!(receipt.fee, 100_000);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // the total fee is transferred once
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000 - 4_000_000 - 200_000);
+    assert_eq!(carbon_client.balance(&treasury), 200_000);
+}
@@ -0,0 +1,200 @@
+use soroban_sdk::{vec, Address, IntoVal, String, Symbol, TryFromVal};
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+use crate::contract::{BatchSinkItem, SinkContractClient, SinkReceipt};
+use crate::errors::SinkError;
+use crate::events::{ForwardEventData, ForwardingChangedEvent};
+use crate::successor::MAX_FORWARD_HOPS;
+use crate::tests::fixtures::{
+    deactivate_with_successor, deploy_sink_contract, set_up_contracts_and_funder, supersede_contract, Setup,
+};
+use crate::tests::utils::last_admin_event;
+
+fn enable_forwarding(setup: &Setup) {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_forwarding",
+                args: (true,).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_forwarding(&true);
+}
+
+fn sink_via(setup: &Setup, client: &SinkContractClient) -> Result<SinkReceipt, SinkError> {
+    let env = &setup.env;
+    client
+        .mock_all_auths()
+        .try_sink_carbon(
+            &setup.funder,
+            &setup.funder,
+            &1_000_000,
+            &Symbol::new(env, "VCS1360"),
+            &String::from_str(env, "100 kg"),
+        )
+        .map(|receipt| receipt.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_set_forwarding() {
+    let setup = set_up_contracts_and_funder(0, None);
+    assert!(!setup.sink_client.is_forwarding());
+
+    enable_forwarding(&setup);
+    let event: ForwardingChangedEvent = last_admin_event(&setup.env, "set_forwarding");
+    assert_eq!(
+        event,
+        ForwardingChangedEvent { admin: setup.carbonsink_issuer.clone(), old_enabled: false, new_enabled: true }
+    );
+    assert!(setup.sink_client.is_forwarding());
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_set_forwarding_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.set_forwarding(&true);
+}
+
+#[test]
+fn test_superseded_without_forwarding() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &successor.address);
+
+    // a superseded contract is distinguishable from a paused one, and has handed the CarbonSINK admin on
+    assert_eq!(sink_via(&setup, client), Err(SinkError::ContractSuperseded));
+    let check = client.check_sink(&setup.funder, &setup.funder, &1_000_000, &Symbol::new(env, "VCS1360"));
+    assert_eq!(
+        check.errors,
+        vec![env, SinkError::ContractSuperseded as u32, SinkError::NotCarbonSinkAdmin as u32]
+    );
+
+    // batches and sinks from an allowance are never forwarded
+    enable_forwarding(&setup);
+    let items = vec![
+        env,
+        BatchSinkItem {
+            recipient: setup.funder.clone(),
+            amount: 1_000_000,
+            project_id: Symbol::new(env, "VCS1360"),
+            memo_text: String::from_str(env, ""),
+        },
+    ];
+    let res = client.mock_all_auths().try_sink_carbon_batch(&setup.funder, &items, &true);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::ContractSuperseded);
+    let operator = Address::generate(env);
+    client.mock_all_auths().set_operator(&setup.funder, &operator, &true);
+    let res = client.mock_all_auths().try_sink_carbon_from(
+        &operator, &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::ContractSuperseded);
+}
+
+#[test]
+fn test_forward_sink() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &successor.address);
+    enable_forwarding(&setup);
+
+    let retirement_id = sink_via(&setup, &setup.sink_client).unwrap().retirement_id;
+
+    // the superseded contract publishes a forward event
+    let (contract_id, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(contract_id, setup.contract_id);
+    assert_eq!(
+        topics,
+        (String::from_str(env, "sink_carbon"), String::from_str(env, "forward")).into_val(env)
+    );
+    assert_eq!(
+        ForwardEventData::try_from_val(env, &data).unwrap(),
+        ForwardEventData {
+            successor: successor.address.clone(),
+            retirement_id,
+            funder: setup.funder.clone(),
+            recipient: setup.funder.clone(),
+        }
+    );
+
+    // the successor has sunk and recorded the retirement
+    let retirement = successor.get_retirement(&retirement_id);
+    assert_eq!(retirement.funder, setup.funder);
+    assert_eq!(retirement.amount, 1_000_000);
+    assert_eq!(successor.get_totals().total_sunk, 1_000_000);
+    assert_eq!(setup.sink_client.get_totals().total_sunk, 0);
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 9_000_000);
+    assert_eq!(carbonsink_client.balance(&setup.funder), 1_000_000);
+}
+
+#[test]
+fn test_forward_sink_through_chain() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let intermediate = deploy_sink_contract(&setup);
+    let latest = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &intermediate.address);
+    deactivate_with_successor(&setup, &intermediate, &latest.address);
+    StellarAssetClient::new(&setup.env, &setup.carbonsink_sac.address())
+        .mock_all_auths()
+        .set_admin(&latest.address);
+    enable_forwarding(&setup);
+
+    // the intermediate contract need not forward itself
+    let retirement_id = sink_via(&setup, &setup.sink_client).unwrap().retirement_id;
+    assert_eq!(latest.get_retirement(&retirement_id).amount, 1_000_000);
+    assert_eq!(intermediate.get_totals().total_sunk, 0);
+}
+
+#[test]
+fn test_forward_sink_successor_deactivated() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &successor.address);
+    successor.mock_all_auths().deactivate(&setup.carbonsink_issuer);
+    enable_forwarding(&setup);
+
+    assert_eq!(sink_via(&setup, &setup.sink_client), Err(SinkError::ContractDeactivated));
+}
+
+#[test]
+fn test_forward_sink_cycle() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &successor.address);
+    deactivate_with_successor(&setup, &successor, &setup.contract_id);
+    enable_forwarding(&setup);
+
+    assert_eq!(sink_via(&setup, &setup.sink_client), Err(SinkError::SuccessorCycle));
+}
+
+#[test]
+fn test_forward_sink_chain_too_long() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let first = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &first.address);
+    enable_forwarding(&setup);
+
+    // a chain of deactivated contracts that is one contract longer than the hop limit
+    let mut current = first;
+    for _ in 0..MAX_FORWARD_HOPS {
+        let next = deploy_sink_contract(&setup);
+        deactivate_with_successor(&setup, &current, &next.address);
+        current = next;
+    }
+
+    assert_eq!(sink_via(&setup, &setup.sink_client), Err(SinkError::SuccessorChainTooLong));
+}
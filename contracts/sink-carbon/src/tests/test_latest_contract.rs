@@ -0,0 +1,91 @@
+use soroban_sdk::Address;
+use soroban_sdk::testutils::Address as _;
+
+use crate::contract::LatestContract;
+use crate::errors::SinkError;
+use crate::successor::MAX_RESOLVE_HOPS;
+use crate::tests::fixtures::{
+    deactivate_with_successor, deploy_sink_contract, set_up_contracts_and_funder, supersede_contract,
+};
+
+#[test]
+fn test_latest_contract_is_active_contract() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let latest = setup.sink_client.get_latest_contract(&MAX_RESOLVE_HOPS);
+    assert_eq!(latest, LatestContract { address: setup.contract_id.clone(), hops: 0 });
+}
+
+#[test]
+fn test_latest_contract_follows_chain() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let intermediate = deploy_sink_contract(&setup);
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &intermediate.address);
+    deactivate_with_successor(&setup, &intermediate, &successor.address);
+
+    let latest = setup.sink_client.get_latest_contract(&2);
+    assert_eq!(latest, LatestContract { address: successor.address.clone(), hops: 2 });
+    let latest = intermediate.get_latest_contract(&2);
+    assert_eq!(latest, LatestContract { address: successor.address.clone(), hops: 1 });
+
+    // the chain is longer than `max_hops`
+    let res = setup.sink_client.try_get_latest_contract(&1);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::SuccessorChainTooLong);
+}
+
+#[test]
+fn test_latest_contract_max_hops_capped() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let first = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &first.address);
+
+    // a chain of deactivated contracts that is one contract longer than the cap
+    let mut current = first;
+    for _ in 0..MAX_RESOLVE_HOPS {
+        let next = deploy_sink_contract(&setup);
+        deactivate_with_successor(&setup, &current, &next.address);
+        current = next;
+    }
+
+    let res = setup.sink_client.try_get_latest_contract(&u32::MAX);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::SuccessorChainTooLong);
+}
+
+#[test]
+fn test_latest_contract_cycle() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let successor = deploy_sink_contract(&setup);
+    supersede_contract(&setup, &successor.address);
+    deactivate_with_successor(&setup, &successor, &setup.contract_id);
+
+    let res = setup.sink_client.try_get_latest_contract(&MAX_RESOLVE_HOPS);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::SuccessorCycle);
+}
+
+#[test]
+fn test_latest_contract_deactivated() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let admin = &setup.carbonsink_issuer;
+
+    // a paused contract without successor
+    setup.sink_client.mock_all_auths().deactivate(admin);
+    let res = setup.sink_client.try_get_latest_contract(&MAX_RESOLVE_HOPS);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::ContractDeactivated);
+
+    // a chain that ends at a paused contract
+    let successor = deploy_sink_contract(&setup);
+    setup.sink_client.mock_all_auths().set_contract_successor(admin, &successor.address);
+    successor.mock_all_auths().deactivate(admin);
+    let res = setup.sink_client.try_get_latest_contract(&MAX_RESOLVE_HOPS);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::ContractDeactivated);
+}
+
+#[test]
+fn test_latest_contract_dead_end() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let not_a_contract = Address::generate(&setup.env);
+    supersede_contract(&setup, &not_a_contract);
+
+    let res = setup.sink_client.try_get_latest_contract(&MAX_RESOLVE_HOPS);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::SuccessorDeadEnd);
+}
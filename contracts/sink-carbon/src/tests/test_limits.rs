@@ -0,0 +1,241 @@
+use soroban_sdk::{vec, Address, IntoVal, String, Symbol};
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+
+use crate::contract::{BatchSinkItem, BatchSinkResult, SinkReceipt};
+use crate::errors::SinkError;
+use crate::events::{MaximumChangedEvent, RateLimitChangedEvent};
+use crate::limits::{RateLimit, MAX_RATE_LIMIT_WINDOW, RATE_LIMIT_BUCKETS};
+use crate::storage_types::DAY_IN_LEDGERS;
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+
+fn sink(setup: &Setup, amount: i64) -> Result<SinkReceipt, SinkError> {
+    let env = &setup.env;
+    setup
+        .sink_client
+        .mock_all_auths()
+        .try_sink_carbon(
+            &setup.funder,
+            &setup.funder,
+            &amount,
+            &Symbol::new(env, "VCS1360"),
+            &String::from_str(env, "limits"),
+        )
+        .map(|receipt| receipt.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+fn set_daily_limit(setup: &Setup, max_amount: i128) {
+    let limit = Some(RateLimit { window_ledgers: DAY_IN_LEDGERS, max_amount });
+    setup.sink_client.mock_all_auths().set_rate_limit(&setup.carbonsink_issuer, &limit);
+}
+
+#[test]
+fn test_limits_default() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    assert_eq!(client.get_maximum_sink_amount(), None);
+    assert_eq!(client.get_rate_limit(), None);
+    assert_eq!(client.get_funder_usage(&setup.funder), 0);
+}
+
+#[test]
+fn test_set_maximum_sink_amount() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+
+    client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_maximum_sink_amount",
+                args: (admin, Some(5_000_000_i64)).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_maximum_sink_amount(admin, &Some(5_000_000));
+    let event: MaximumChangedEvent = last_admin_event(env, "set_maximum_sink_amount");
+    assert_eq!(
+        event,
+        MaximumChangedEvent { admin: admin.clone(), old_maximum: None, new_maximum: Some(5_000_000) }
+    );
+    assert_eq!(client.get_maximum_sink_amount(), Some(5_000_000));
+    assert_eq!(client.get_config().sink_maximum, Some(5_000_000));
+
+    // the maximum can be removed again
+    client.mock_all_auths().set_maximum_sink_amount(admin, &None);
+    assert_eq!(client.get_maximum_sink_amount(), None);
+
+    let res = client.mock_all_auths().try_set_maximum_sink_amount(admin, &Some(-1));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::NegativeAmount);
+    let res = client.mock_all_auths().try_set_maximum_sink_amount(&Address::generate(env), &Some(0));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_sink_amount_too_high() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    client.mock_all_auths().set_maximum_sink_amount(&setup.carbonsink_issuer, &Some(5_000_000));
+    let check = client.check_sink(&setup.funder, &setup.funder, &5_010_000, &Symbol::new(env, "VCS1360"));
+    assert_eq!(check.errors, vec![env, SinkError::AmountTooHigh as u32]);
+
+    // the maximum applies to the quantized amount
+    assert_eq!(sink(&setup, 5_009_999).unwrap().amount, 5_000_000);
+    assert_eq!(sink(&setup, 5_010_000), Err(SinkError::AmountTooHigh));
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 5_000_000);
+
+    // the maximum applies to each item of a batch
+    let item = |amount: i64| BatchSinkItem {
+        recipient: setup.funder.clone(),
+        amount,
+        project_id: Symbol::new(env, "VCS1360"),
+        memo_text: String::from_str(env, ""),
+    };
+    let results = client.mock_all_auths().sink_carbon_batch(
+        &setup.funder, &vec![env, item(5_010_000), item(1_000_000)], &false
+    );
+    assert_eq!(results.get_unchecked(0), BatchSinkResult::Failed(SinkError::AmountTooHigh as u32));
+    assert!(matches!(results.get_unchecked(1), BatchSinkResult::Sunk(_)));
+}
+
+#[test]
+fn test_set_rate_limit() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+
+    let limit = Some(RateLimit { window_ledgers: DAY_IN_LEDGERS, max_amount: 10_000_000 });
+    client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_rate_limit",
+                args: (admin, limit.clone()).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_rate_limit(admin, &limit);
+    let event: RateLimitChangedEvent = last_admin_event(env, "set_rate_limit");
+    assert_eq!(
+        event,
+        RateLimitChangedEvent {
+            admin: admin.clone(),
+            old_window_ledgers: 0,
+            new_window_ledgers: DAY_IN_LEDGERS,
+            old_max_amount: 0,
+            new_max_amount: 10_000_000,
+        }
+    );
+    assert_eq!(client.get_rate_limit(), limit);
+    let config = client.get_config();
+    assert_eq!((config.rate_limit_window_ledgers, config.rate_limit_max_amount), (DAY_IN_LEDGERS, 10_000_000));
+
+    let client = client.mock_all_auths();
+    for invalid_limit in [
+        RateLimit { window_ledgers: 0, max_amount: 10_000_000 },
+        RateLimit { window_ledgers: MAX_RATE_LIMIT_WINDOW + 1, max_amount: 10_000_000 },
+        RateLimit { window_ledgers: DAY_IN_LEDGERS, max_amount: -1 },
+    ] {
+        let res = client.try_set_rate_limit(admin, &Some(invalid_limit));
+        assert_eq!(res.unwrap_err().unwrap(), SinkError::InvalidRateLimit);
+    }
+    let res = client.try_set_rate_limit(&Address::generate(env), &None);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_rate_limited() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    set_daily_limit(&setup, 3_000_000);
+
+    sink(&setup, 2_000_000).unwrap();
+    assert_eq!(client.get_funder_usage(&setup.funder), 2_000_000);
+    assert_eq!(sink(&setup, 2_000_000), Err(SinkError::RateLimited));
+    let check = client.check_sink(&setup.funder, &setup.funder, &2_000_000, &Symbol::new(env, "VCS1360"));
+    assert_eq!(check.errors, vec![env, SinkError::RateLimited as u32]);
+
+    // the remainder of the cap can still be sunk
+    sink(&setup, 1_000_000).unwrap();
+    assert_eq!(sink(&setup, 1_000_000), Err(SinkError::RateLimited));
+
+    // other funders have their own window
+    let other_funder = Address::generate(env);
+    let check = client.check_sink(&other_funder, &other_funder, &2_000_000, &Symbol::new(env, "VCS1360"));
+    assert!(!check.errors.contains(SinkError::RateLimited as u32));
+
+    // the sinks count for a day, and for at most one bucket longer
+    let bucket_ledgers = DAY_IN_LEDGERS / RATE_LIMIT_BUCKETS;
+    env.ledger().with_mut(|li| li.sequence_number += DAY_IN_LEDGERS - 1);
+    assert_eq!(sink(&setup, 1_000_000), Err(SinkError::RateLimited));
+    env.ledger().with_mut(|li| li.sequence_number += bucket_ledgers);
+    assert_eq!(client.get_funder_usage(&setup.funder), 0);
+    sink(&setup, 2_000_000).unwrap();
+    assert_eq!(client.get_funder_usage(&setup.funder), 2_000_000);
+
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 5_000_000);
+}
+
+#[test]
+fn test_rate_limit_window_slides() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    set_daily_limit(&setup, 3_000_000);
+    let bucket_ledgers = DAY_IN_LEDGERS / RATE_LIMIT_BUCKETS;
+
+    // sink the cap in two parts, half a day apart
+    sink(&setup, 2_000_000).unwrap();
+    env.ledger().with_mut(|li| li.sequence_number += DAY_IN_LEDGERS / 2);
+    sink(&setup, 1_000_000).unwrap();
+
+    // once the first sink has left the window, only its amount can be sunk again
+    env.ledger().with_mut(|li| li.sequence_number += DAY_IN_LEDGERS / 2 + bucket_ledgers);
+    assert_eq!(client.get_funder_usage(&setup.funder), 1_000_000);
+    assert_eq!(sink(&setup, 3_000_000), Err(SinkError::RateLimited));
+    sink(&setup, 2_000_000).unwrap();
+    assert_eq!(sink(&setup, 1_000_000), Err(SinkError::RateLimited));
+
+    // a funder can never sink more than the cap within any day
+    env.ledger().with_mut(|li| li.sequence_number += DAY_IN_LEDGERS / 2 + bucket_ledgers);
+    assert_eq!(client.get_funder_usage(&setup.funder), 2_000_000);
+    sink(&setup, 1_000_000).unwrap();
+    assert_eq!(sink(&setup, 1_000_000), Err(SinkError::RateLimited));
+
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 4_000_000);
+}
+
+#[test]
+fn test_rate_limited_batch() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    set_daily_limit(&setup, 1_500_000);
+
+    // the rate limit applies to the sum of the sunk items
+    let item = BatchSinkItem {
+        recipient: setup.funder.clone(),
+        amount: 1_000_000,
+        project_id: Symbol::new(env, "VCS1360"),
+        memo_text: String::from_str(env, ""),
+    };
+    let items = vec![env, item.clone(), item];
+    let results = setup.sink_client.mock_all_auths().sink_carbon_batch(&setup.funder, &items, &false);
+    assert!(matches!(results.get_unchecked(0), BatchSinkResult::Sunk(_)));
+    assert_eq!(results.get_unchecked(1), BatchSinkResult::Failed(SinkError::RateLimited as u32));
+
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 9_000_000);
+    assert_eq!(setup.sink_client.get_funder_usage(&setup.funder), 1_000_000);
+}
@@ -0,0 +1,237 @@
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, IntoVal, String, Symbol, TryFromVal};
+
+use crate::errors::SinkError;
+use crate::events::{InventoryChangedEvent, ProjectChangedEvent};
+use crate::storage_types::Project;
+use crate::tests::fixtures::{
+    add_project_as_admin, add_project_inventory_as_admin, set_up_contracts_and_funder, test_project_info,
+    TEST_PROJECTS,
+};
+use crate::tests::utils::{sink_carbon_with_auth, SinkTestData};
+
+#[test]
+fn test_get_and_list_projects() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+
+    let project = client.get_project(&Symbol::new(env, "VCS1360"));
+    assert_eq!(
+        project,
+        Project {
+            project_id: Symbol::new(env, "VCS1360"),
+            info: test_project_info(env),
+            is_listed: true,
+        }
+    );
+
+    // unknown projects should return an error
+    let unknown = client.try_get_project(&Symbol::new(env, "VCS136O"));
+    assert_eq!(unknown.unwrap_err().unwrap(), SinkError::UnknownProject);
+
+    // projects are listed in order of registration
+    let first_page = client.list_projects(&0, &3);
+    assert_eq!(first_page.len(), 3);
+    for (position, project) in first_page.iter().enumerate() {
+        assert_eq!(project.project_id, Symbol::new(env, TEST_PROJECTS[position]));
+    }
+    let second_page = client.list_projects(&3, &3);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get_unchecked(0).project_id, Symbol::new(env, "max_impact"));
+}
+
+#[test]
+fn test_add_project_twice() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+
+    // the fixture has already listed this project
+    let add_res = client
+        .mock_all_auths()
+        .try_add_project(&Symbol::new(env, "VCS1360"), &test_project_info(env));
+    assert_eq!(add_res.unwrap_err().unwrap(), SinkError::ProjectAlreadyListed);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_add_project_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let project_id = Symbol::new(env, "VCS934");
+    let info = test_project_info(env);
+
+    // adding a project as a random address should fail
+    client
+        .mock_auths(&[MockAuth {
+            address: &Address::generate(env),
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "add_project",
+                args: (&project_id, info.clone()).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .add_project(&project_id, &info);
+}
+
+#[test]
+fn test_update_project() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let project_id = Symbol::new(env, "VCS1360");
+
+    let mut info = test_project_info(env);
+    info.vintage = 2024;
+    info.metadata_uri = String::from_str(env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+    client.mock_all_auths().update_project(&project_id, &info);
+
+    let project = client.get_project(&project_id);
+    assert_eq!(project.info, info);
+    assert!(project.is_listed);
+
+    // unknown projects cannot be updated
+    let update_res = client.mock_all_auths().try_update_project(&Symbol::new(env, "VCS136O"), &info);
+    assert_eq!(update_res.unwrap_err().unwrap(), SinkError::UnknownProject);
+}
+
+#[test]
+fn test_sink_unknown_project() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+
+    // attempt to sink 0.1 CARBON for a misspelled project
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS136O",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::UnknownProject);
+}
+
+#[test]
+fn test_delist_and_relist_project() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let project_id = Symbol::new(env, "VCS1360");
+
+    client.mock_all_auths().delist_project(&project_id);
+    assert!(!client.get_project(&project_id).is_listed);
+    // delisted projects remain in the registry listing
+    assert_eq!(client.list_projects(&0, &10).len(), TEST_PROJECTS.len() as u32);
+
+    // sinking for a delisted project should fail
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::ProjectDelisted);
+
+    // relisting the project allows sinking again
+    add_project_as_admin(client, &setup.carbonsink_issuer, &project_id);
+    assert!(client.get_project(&project_id).is_listed);
+    assert_eq!(client.list_projects(&0, &10).len(), TEST_PROJECTS.len() as u32);
+    assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+}
+
+#[test]
+fn test_project_inventory_is_consumed() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+    let project_id = Symbol::new(env, "VCS934");
+
+    // a newly listed project has no inventory
+    add_project_as_admin(client, admin, &project_id);
+    assert_eq!(client.get_project_inventory(&project_id), 0);
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS934",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::ProjectInventoryExhausted);
+
+    // top up the inventory with a batch of 0.15 t
+    add_project_inventory_as_admin(client, admin, &project_id, 1_500_000);
+    assert_eq!(client.get_project_inventory(&project_id), 1_500_000);
+
+    // the quantized amount is deducted from the inventory
+    let mut test_data = test_data.clone();
+    test_data.amount = 1_009_999_i64;
+    assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+    assert_eq!(client.get_project_inventory(&project_id), 500_000);
+
+    // the remaining inventory is insufficient for another 0.1 t
+    test_data.amount = 1_000_000_i64;
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::ProjectInventoryExhausted);
+    assert_eq!(client.get_project_inventory(&project_id), 500_000);
+}
+
+#[test]
+fn test_add_project_inventory_invalid() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+
+    let unknown = client.try_add_project_inventory(&Symbol::new(env, "VCS136O"), &1_000_000);
+    assert_eq!(unknown.unwrap_err().unwrap(), SinkError::UnknownProject);
+
+    let negative = client.try_add_project_inventory(&Symbol::new(env, "VCS1360"), &-1);
+    assert_eq!(negative.unwrap_err().unwrap(), SinkError::NegativeAmount);
+}
+
+#[test]
+fn test_project_admin_events() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let admin = setup.carbonsink_issuer;
+    let project_id = Symbol::new(env, "VCS1360");
+
+    client.delist_project(&project_id);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            String::from_str(env, "sink_carbon"),
+            String::from_str(env, "admin"),
+            String::from_str(env, "delist_project"),
+        ).into_val(env)
+    );
+    assert_eq!(
+        ProjectChangedEvent::try_from_val(env, &data).unwrap(),
+        ProjectChangedEvent {
+            admin: admin.clone(),
+            project_id: project_id.clone(),
+            old_is_listed: true,
+            new_is_listed: false,
+        }
+    );
+
+    client.add_project_inventory(&project_id, &1_000_000);
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        InventoryChangedEvent::try_from_val(env, &data).unwrap(),
+        InventoryChangedEvent {
+            admin,
+            project_id,
+            old_inventory: i64::MAX as i128,
+            new_inventory: i64::MAX as i128 + 1_000_000,
+        }
+    );
+}
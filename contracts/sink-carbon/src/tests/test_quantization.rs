@@ -0,0 +1,148 @@
+use soroban_sdk::{Address, IntoVal, String, Symbol, TryFromVal};
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+
+use crate::errors::SinkError;
+use crate::events::QuantizationChangedEvent;
+use crate::storage_types::{QuantizationUnit, RoundingPolicy};
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::utils::quantize;
+
+fn set_quantization_as_admin(setup: &Setup, unit: QuantizationUnit, rounding: RoundingPolicy) {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_quantization",
+                args: (&setup.carbonsink_issuer, unit, rounding).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_quantization(&setup.carbonsink_issuer, &unit, &rounding);
+}
+
+#[test]
+fn test_quantize_truncate() {
+    let truncate = RoundingPolicy::Truncate;
+    assert_eq!(quantize(50_000_123, QuantizationUnit::Kilogram, truncate), Ok(50_000_000));
+    assert_eq!(quantize(999_999, QuantizationUnit::Kilogram, truncate), Ok(990_000));
+    assert_eq!(quantize(999_999, QuantizationUnit::Gram, truncate), Ok(999_990));
+    assert_eq!(quantize(19_999_999, QuantizationUnit::Tonne, truncate), Ok(10_000_000));
+}
+
+#[test]
+fn test_quantize_strict() {
+    let strict = RoundingPolicy::Strict;
+    assert_eq!(quantize(1_230_000, QuantizationUnit::Kilogram, strict), Ok(1_230_000));
+    assert_eq!(quantize(1_234_567, QuantizationUnit::Kilogram, strict), Err(SinkError::AmountNotAligned));
+    assert_eq!(quantize(1_234_560, QuantizationUnit::Gram, strict), Ok(1_234_560));
+    assert_eq!(quantize(1_230_000, QuantizationUnit::Tonne, strict), Err(SinkError::AmountNotAligned));
+}
+
+#[test]
+fn test_quantize_nearest() {
+    let nearest = RoundingPolicy::Nearest;
+    assert_eq!(quantize(1_234_999, QuantizationUnit::Kilogram, nearest), Ok(1_230_000));
+    assert_eq!(quantize(1_235_000, QuantizationUnit::Kilogram, nearest), Ok(1_240_000));
+    assert_eq!(quantize(15_000_000, QuantizationUnit::Tonne, nearest), Ok(20_000_000));
+    // rounding up must not overflow
+    assert_eq!(quantize(i64::MAX, QuantizationUnit::Kilogram, nearest), Ok(i64::MAX - 5_807));
+}
+
+#[test]
+fn test_quantization_defaults() {
+    let setup = set_up_contracts_and_funder(0, None);
+    assert_eq!(setup.sink_client.get_quantization_unit(), QuantizationUnit::Kilogram);
+    assert_eq!(setup.sink_client.get_rounding_policy(), RoundingPolicy::Truncate);
+}
+
+#[test]
+fn test_set_quantization_as_admin() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    set_quantization_as_admin(&setup, QuantizationUnit::Gram, RoundingPolicy::Strict);
+    let (_, _, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        QuantizationChangedEvent::try_from_val(env, &data).unwrap(),
+        QuantizationChangedEvent {
+            admin: setup.carbonsink_issuer.clone(),
+            old_unit: QuantizationUnit::Kilogram,
+            new_unit: QuantizationUnit::Gram,
+            old_rounding: RoundingPolicy::Truncate,
+            new_rounding: RoundingPolicy::Strict,
+        }
+    );
+
+    assert_eq!(setup.sink_client.get_quantization_unit(), QuantizationUnit::Gram);
+    assert_eq!(setup.sink_client.get_rounding_policy(), RoundingPolicy::Strict);
+}
+
+#[test]
+fn test_set_quantization_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    let caller = Address::generate(&setup.env);
+    let args = (&caller, QuantizationUnit::Tonne, RoundingPolicy::Nearest);
+
+    // a random address is not a config manager
+    let res = client
+        .mock_auths(&[MockAuth {
+            address: &caller,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_quantization",
+                args: args.into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_quantization(args.0, &args.1, &args.2);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_sink_carbon_strict() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let project_id = Symbol::new(env, "VCS1360");
+    let memo_text = String::from_str(env, "invoice 42");
+    set_quantization_as_admin(&setup, QuantizationUnit::Kilogram, RoundingPolicy::Strict);
+
+    // a non-aligned amount is rejected, instead of being truncated
+    let sink_res = client.mock_all_auths().try_sink_carbon(
+        &setup.funder, &setup.funder, &1_234_567, &project_id, &memo_text
+    );
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::AmountNotAligned);
+    let check = client.check_sink(&setup.funder, &setup.funder, &1_234_567, &project_id);
+    assert_eq!(check.errors.get(0), Some(SinkError::AmountNotAligned as u32));
+
+    // an aligned amount is burned exactly
+    client.mock_all_auths().sink_carbon(&setup.funder, &setup.funder, &1_230_000, &project_id, &memo_text);
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 8_770_000);
+}
+
+#[test]
+fn test_sink_carbon_nearest_gram() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    set_quantization_as_admin(&setup, QuantizationUnit::Gram, RoundingPolicy::Nearest);
+
+    // 0.1234567 CARBON rounds up to 123.457 kg
+    let project_id = Symbol::new(env, "VCS1360");
+    let check = setup.sink_client.check_sink(&setup.funder, &setup.funder, &1_234_567, &project_id);
+    assert_eq!(check.amount, 1_234_570);
+    assert_eq!(check.remainder, -3);
+    let receipt = setup.sink_client.mock_all_auths().sink_carbon(
+        &setup.funder, &setup.funder, &1_234_567, &project_id, &String::from_str(env, "")
+    );
+    assert_eq!(receipt.amount, 1_234_570);
+    assert_eq!(receipt.remainder, -3);
+
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000 - 1_234_570);
+    assert_eq!(carbonsink_client.balance(&setup.funder), 1_234_570);
+}
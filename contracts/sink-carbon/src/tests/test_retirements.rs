@@ -0,0 +1,217 @@
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, String, Symbol};
+
+use crate::errors::SinkError;
+use crate::retirements::{get_retirement_count, read_retirement, MAX_PAGE_SIZE};
+use crate::storage_types::{Retirement, SinkTotals};
+use crate::tests::fixtures::set_up_contracts_and_funder;
+use crate::tests::utils::{sink_carbon_with_auth, SinkTestData};
+
+#[test]
+fn test_sink_carbon_records_retirement() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1_234;
+        li.timestamp = 1_700_000_000;
+    });
+    let recipient = Address::generate(env);
+
+    // have the funder sink 0.1234567 CARBON for the recipient
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &recipient,
+        amount: 1_234_567_i64,
+        project_id: "VCS1360",
+        memo_text: "123 kg 🌳🌴",
+    };
+    let retirement_id = sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id;
+    assert_eq!(retirement_id, 1);
+
+    // the stored record should contain the quantized amount
+    let retirement = env.as_contract(&setup.contract_id, || read_retirement(env, retirement_id));
+    assert_eq!(
+        retirement,
+        Some(Retirement {
+            id: 1,
+            funder: setup.funder.clone(),
+            recipient,
+            amount: 1_230_000,
+            project_id: Symbol::new(env, "VCS1360"),
+            memo_text: String::from_str(env, "123 kg 🌳🌴"),
+            ledger: 1_234,
+            timestamp: 1_700_000_000,
+        })
+    );
+}
+
+#[test]
+fn test_retirement_ids_are_sequential() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    assert_eq!(sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id, 1);
+    assert_eq!(sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id, 2);
+
+    // a failed sink should not consume an ID
+    let mut too_low = test_data.clone();
+    too_low.amount = 990_000_i64;
+    assert_eq!(sink_carbon_with_auth(&setup, &too_low), Err(SinkError::AmountTooLow));
+    assert_eq!(sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id, 3);
+
+    env.as_contract(&setup.contract_id, || {
+        assert_eq!(get_retirement_count(env), 3);
+        assert!(read_retirement(env, 0).is_none());
+        assert!(read_retirement(env, 4).is_none());
+    });
+}
+
+#[test]
+fn test_get_retirement() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let client = &setup.sink_client;
+
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let retirement_id = sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id;
+
+    let retirement = client.get_retirement(&retirement_id);
+    assert_eq!(retirement.id, retirement_id);
+    assert_eq!(retirement.funder, setup.funder);
+    assert_eq!(retirement.amount, 1_000_000);
+
+    // unknown IDs should return an error
+    let not_found = client.try_get_retirement(&(retirement_id + 1));
+    assert_eq!(not_found.unwrap_err().unwrap(), SinkError::RetirementNotFound);
+}
+
+#[test]
+fn test_list_retirements_by_recipient_and_funder() {
+    let setup = set_up_contracts_and_funder(100_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let recipient_a = Address::generate(env);
+    let recipient_b = Address::generate(env);
+
+    // sink three times for recipient A and twice for recipient B
+    for (recipient, amount) in [
+        (&recipient_a, 1_000_000_i64),
+        (&recipient_b, 2_000_000_i64),
+        (&recipient_a, 3_000_000_i64),
+        (&recipient_b, 4_000_000_i64),
+        (&recipient_a, 5_000_000_i64),
+    ] {
+        let test_data = SinkTestData {
+            funder: &setup.funder,
+            recipient,
+            amount,
+            project_id: "VCS1360",
+            memo_text: "",
+        };
+        assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+    }
+
+    // page through recipient A's retirements
+    let first_page = client.list_retirements_by_recipient(&recipient_a, &0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get_unchecked(0).id, 1);
+    assert_eq!(first_page.get_unchecked(1).id, 3);
+    let second_page = client.list_retirements_by_recipient(&recipient_a, &2, &2);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get_unchecked(0).id, 5);
+    assert_eq!(second_page.get_unchecked(0).amount, 5_000_000);
+    let past_the_end = client.list_retirements_by_recipient(&recipient_a, &3, &2);
+    assert_eq!(past_the_end.len(), 0);
+
+    // recipient B has its own listing
+    let recipient_b_page = client.list_retirements_by_recipient(&recipient_b, &0, &10);
+    assert_eq!(recipient_b_page.len(), 2);
+    assert_eq!(recipient_b_page.get_unchecked(1).id, 4);
+
+    // the funder listing contains all retirements
+    let funder_page = client.list_retirements_by_funder(&setup.funder, &1, &10);
+    assert_eq!(funder_page.len(), 4);
+    assert_eq!(funder_page.get_unchecked(0).id, 2);
+    assert_eq!(funder_page.get_unchecked(3).id, 5);
+
+    // addresses without retirements have empty listings
+    assert_eq!(client.list_retirements_by_funder(&recipient_a, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_list_retirements_page_size_is_capped() {
+    let setup = set_up_contracts_and_funder(1_000_000_000, None);
+    let client = &setup.sink_client;
+
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "",
+    };
+    for _ in 0..(MAX_PAGE_SIZE + 1) {
+        assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+    }
+
+    let page = client.list_retirements_by_recipient(&setup.funder, &0, &u32::MAX);
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
+    let last_page = client.list_retirements_by_recipient(&setup.funder, &MAX_PAGE_SIZE, &u32::MAX);
+    assert_eq!(last_page.len(), 1);
+}
+
+#[test]
+fn test_cumulative_totals() {
+    let setup = set_up_contracts_and_funder(100_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let recipient = Address::generate(env);
+
+    // initially, all totals are zero
+    assert_eq!(
+        client.get_totals(),
+        SinkTotals { total_sunk: 0, sink_count: 0, unique_recipients: 0 }
+    );
+    assert_eq!(client.get_recipient_total(&recipient), 0);
+
+    // sink twice for the funder itself, and once for a separate recipient
+    for (recipient, amount, project_id) in [
+        (&setup.funder, 1_000_000_i64, "VCS1360"),
+        (&recipient, 2_345_678_i64, "VCS1360"),
+        (&setup.funder, 3_000_000_i64, "OFP1234567890"),
+    ] {
+        let test_data = SinkTestData {
+            funder: &setup.funder,
+            recipient,
+            amount,
+            project_id,
+            memo_text: "",
+        };
+        assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+    }
+
+    // totals are accumulated using the quantized amounts
+    assert_eq!(
+        client.get_totals(),
+        SinkTotals { total_sunk: 6_340_000, sink_count: 3, unique_recipients: 2 }
+    );
+    assert_eq!(client.get_recipient_total(&setup.funder), 4_000_000);
+    assert_eq!(client.get_recipient_total(&recipient), 2_340_000);
+    assert_eq!(client.get_funder_total(&setup.funder), 6_340_000);
+    assert_eq!(client.get_funder_total(&recipient), 0);
+    assert_eq!(client.get_project_total(&Symbol::new(env, "VCS1360")), 3_340_000);
+    assert_eq!(client.get_project_total(&Symbol::new(env, "OFP1234567890")), 3_000_000);
+    assert_eq!(client.get_project_total(&Symbol::new(env, "SOMEPROJECT")), 0);
+}
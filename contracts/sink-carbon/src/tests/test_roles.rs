@@ -0,0 +1,165 @@
+use soroban_sdk::{Address, IntoVal};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::StellarAssetClient;
+
+use crate::errors::SinkError;
+use crate::events::{ActiveChangedEvent, RoleChangedEvent};
+use crate::roles::Role;
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+
+fn grant_role_as_admin(setup: &Setup, role: Role, account: &Address) {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "grant_role",
+                args: (role, account).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .grant_role(&role, account);
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+    let pauser = Address::generate(env);
+    assert!(!client.has_role(&Role::Pauser, &pauser));
+
+    grant_role_as_admin(&setup, Role::Pauser, &pauser);
+    let event: RoleChangedEvent = last_admin_event(env, "grant_role");
+    assert_eq!(
+        event,
+        RoleChangedEvent {
+            admin: admin.clone(),
+            role: Role::Pauser,
+            account: pauser.clone(),
+            old_has_role: false,
+            new_has_role: true,
+        }
+    );
+    assert!(client.has_role(&Role::Pauser, &pauser));
+    assert!(!client.has_role(&Role::ConfigManager, &pauser));
+
+    client.mock_all_auths().revoke_role(&Role::Pauser, &pauser);
+    let event: RoleChangedEvent = last_admin_event(env, "revoke_role");
+    assert_eq!(
+        event,
+        RoleChangedEvent {
+            admin: admin.clone(),
+            role: Role::Pauser,
+            account: pauser.clone(),
+            old_has_role: true,
+            new_has_role: false,
+        }
+    );
+    assert!(!client.has_role(&Role::Pauser, &pauser));
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_grant_role_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    let account = Address::generate(&setup.env);
+
+    // only the admin can grant roles
+    client
+        .mock_auths(&[MockAuth {
+            address: &account,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "grant_role",
+                args: (Role::Pauser, &account).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .grant_role(&Role::Pauser, &account);
+}
+
+#[test]
+fn test_pauser_can_deactivate() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let pauser = Address::generate(env);
+    grant_role_as_admin(&setup, Role::Pauser, &pauser);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &pauser,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "deactivate",
+                args: (&pauser,).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .deactivate(&pauser);
+    let event: ActiveChangedEvent = last_admin_event(env, "deactivate");
+    assert_eq!(event, ActiveChangedEvent { admin: pauser.clone(), old_is_active: true, new_is_active: false });
+    assert!(!client.is_active());
+
+    // the pauser cannot change the configuration
+    let res = client.mock_all_auths().try_set_minimum_sink_amount(&pauser, &0);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_role_required() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = setup.sink_client.mock_all_auths();
+    let caller = Address::generate(&setup.env);
+
+    assert_eq!(client.try_deactivate(&caller).unwrap_err().unwrap(), SinkError::Unauthorized);
+    assert_eq!(client.try_set_fee_bps(&caller, &100).unwrap_err().unwrap(), SinkError::Unauthorized);
+    assert_eq!(
+        client.try_set_contract_successor(&caller, &caller).unwrap_err().unwrap(),
+        SinkError::Unauthorized
+    );
+    assert_eq!(client.try_reset_admin(&caller).unwrap_err().unwrap(), SinkError::Unauthorized);
+    assert!(client.is_active());
+}
+
+#[test]
+fn test_config_manager() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    let manager = Address::generate(&setup.env);
+    grant_role_as_admin(&setup, Role::ConfigManager, &manager);
+
+    client.mock_all_auths().set_minimum_sink_amount(&manager, &500_000);
+    client.mock_all_auths().set_fee_bps(&manager, &100);
+    assert_eq!(client.get_minimum_sink_amount(), 500_000);
+    assert_eq!(client.get_fee_bps(), 100);
+
+    // the config manager cannot pause the contract
+    let res = client.mock_all_auths().try_deactivate(&manager);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_upgrade_manager() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let manager = Address::generate(env);
+    let successor = Address::generate(env);
+    grant_role_as_admin(&setup, Role::UpgradeManager, &manager);
+
+    client.mock_all_auths().set_contract_successor(&manager, &successor);
+    assert_eq!(client.get_contract_successor(), successor);
+
+    // the CarbonSINK SAC admin is handed to the admin, not to the upgrade manager
+    let admin = client.mock_all_auths().reset_admin(&manager);
+    assert_eq!(admin, setup.carbonsink_issuer);
+    let carbonsink_client = StellarAssetClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbonsink_client.admin(), setup.carbonsink_issuer);
+    assert!(!client.is_active());
+}
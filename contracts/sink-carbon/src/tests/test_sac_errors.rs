@@ -0,0 +1,150 @@
+use soroban_sdk::{Address, Error, IntoVal, String};
+use soroban_sdk::testutils::{Address as _, Events, IssuerFlags};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+use crate::errors::{map_authorize_error, map_burn_error, map_mint_error, SACError, SinkError};
+use crate::tests::fixtures::set_up_contracts_and_funder;
+use crate::tests::utils::{sink_carbon_with_auth, SinkTestData};
+
+const ALL_SAC_ERRORS: [SACError; 13] = [
+    SACError::InternalError,
+    SACError::OperationNotSupportedError,
+    SACError::AlreadyInitializedError,
+    SACError::UnauthorizedError,
+    SACError::AuthenticationError,
+    SACError::AccountMissingError,
+    SACError::AccountIsNotClassic,
+    SACError::NegativeAmountError,
+    SACError::AllowanceError,
+    SACError::BalanceError,
+    SACError::BalanceDeauthorizedError,
+    SACError::OverflowError,
+    SACError::TrustlineMissingError,
+];
+
+#[test]
+fn test_map_burn_error() {
+    for sac_error in ALL_SAC_ERRORS {
+        let expected = match sac_error {
+            SACError::BalanceError => SinkError::InsufficientBalance,
+            SACError::AccountMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::TrustlineMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::AllowanceError => SinkError::InsufficientAllowance,
+            SACError::BalanceDeauthorizedError => SinkError::FunderBalanceDeauthorized,
+            SACError::NegativeAmountError => SinkError::NegativeAmount,
+            _ => SinkError::CarbonSacError,
+        };
+        assert_eq!(map_burn_error(sac_error.into()), expected, "{:?}", sac_error);
+    }
+}
+
+#[test]
+fn test_map_authorize_error() {
+    for sac_error in ALL_SAC_ERRORS {
+        let expected = match sac_error {
+            SACError::AccountMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::TrustlineMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::UnauthorizedError => SinkError::NotCarbonSinkAdmin,
+            SACError::AuthenticationError => SinkError::NotCarbonSinkAdmin,
+            _ => SinkError::CarbonSinkSacError,
+        };
+        assert_eq!(map_authorize_error(sac_error.into()), expected, "{:?}", sac_error);
+    }
+}
+
+#[test]
+fn test_map_mint_error() {
+    for sac_error in ALL_SAC_ERRORS {
+        let expected = match sac_error {
+            SACError::BalanceError => SinkError::TrustlineLimitReached,
+            SACError::OverflowError => SinkError::TrustlineLimitReached,
+            SACError::AccountMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::TrustlineMissingError => SinkError::AccountOrTrustlineMissing,
+            SACError::BalanceDeauthorizedError => SinkError::RecipientBalanceDeauthorized,
+            SACError::UnauthorizedError => SinkError::NotCarbonSinkAdmin,
+            SACError::AuthenticationError => SinkError::NotCarbonSinkAdmin,
+            SACError::NegativeAmountError => SinkError::NegativeAmount,
+            _ => SinkError::CarbonSinkSacError,
+        };
+        assert_eq!(map_mint_error(sac_error.into()), expected, "{:?}", sac_error);
+    }
+}
+
+#[test]
+fn test_map_unknown_error_code() {
+    // codes that the SAC does not define fall back to the generic errors
+    let unknown = Error::from_contract_error(999);
+    assert_eq!(map_burn_error(unknown), SinkError::CarbonSacError);
+    assert_eq!(map_authorize_error(unknown), SinkError::CarbonSinkSacError);
+    assert_eq!(map_mint_error(unknown), SinkError::CarbonSinkSacError);
+}
+
+#[test]
+fn test_funder_balance_deauthorized() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+
+    // have the CARBON issuer freeze the funder's balance
+    setup.carbon_sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    StellarAssetClient::new(env, &setup.carbon_sac.address())
+        .mock_all_auths()
+        .set_authorized(&setup.funder, &false);
+
+    // attempt to sink 0.1 CARBON
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    // it should fail because the funder's CARBON balance is deauthorized
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::FunderBalanceDeauthorized);
+
+    // the funder's balance is untouched
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000);
+}
+
+#[test]
+fn test_sink_carbon_not_carbonsink_admin() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+
+    // hand the CarbonSINK SAC admin back to the issuer, but keep the contract active
+    client.mock_all_auths().reset_admin(&setup.carbonsink_issuer);
+    client.mock_all_auths().activate();
+
+    // attempt to sink 0.1 CARBON for a separate recipient
+    let recipient = Address::generate(env);
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &recipient,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    // it should fail because the issuer does not authorize `set_authorized`
+    let sink_res = sink_carbon_with_auth(&setup, &test_data);
+    assert_eq!(sink_res.unwrap_err(), SinkError::NotCarbonSinkAdmin);
+
+    // the failed invocation is not hidden from the contract's events
+    let (contract_id, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(contract_id, setup.contract_id);
+    assert_eq!(
+        topics,
+        (
+            String::from_str(env, "sink_carbon"),
+            String::from_str(env, "invoke_error"),
+            String::from_str(env, "set_authorized"),
+        ).into_val(env)
+    );
+
+    // no CarbonSINK was minted, and the CARBON burn was rolled back
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000);
+    assert_eq!(carbonsink_client.balance(&recipient), 0);
+}
@@ -0,0 +1,196 @@
+use soroban_sdk::{vec, Address, IntoVal, String, Symbol};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+
+use crate::contract::{BatchSinkItem, BatchSinkResult, SinkReceipt};
+use crate::errors::SinkError;
+use crate::events::{AllowlistOnlyChangedEvent, ListChangedEvent};
+use crate::screening::ScreeningList;
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+
+fn sink_for(setup: &Setup, recipient: &Address) -> Result<SinkReceipt, SinkError> {
+    let env = &setup.env;
+    setup
+        .sink_client
+        .mock_all_auths()
+        .try_sink_carbon(
+            &setup.funder,
+            recipient,
+            &1_000_000,
+            &Symbol::new(env, "VCS1360"),
+            &String::from_str(env, "screened"),
+        )
+        .map(|receipt| receipt.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_add_and_remove_from_list() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+    let accounts = [Address::generate(env), Address::generate(env), Address::generate(env)];
+
+    client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "add_to_list",
+                args: (ScreeningList::Denylist, &accounts[0]).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .add_to_list(&ScreeningList::Denylist, &accounts[0]);
+    let event: ListChangedEvent = last_admin_event(env, "add_to_list");
+    assert_eq!(
+        event,
+        ListChangedEvent {
+            admin: admin.clone(),
+            list: ScreeningList::Denylist,
+            account: accounts[0].clone(),
+            old_listed: false,
+            new_listed: true,
+        }
+    );
+    assert!(client.is_listed(&ScreeningList::Denylist, &accounts[0]));
+    assert!(!client.is_listed(&ScreeningList::Allowlist, &accounts[0]));
+
+    // adding an address twice has no effect on the list
+    let client = client.mock_all_auths();
+    client.add_to_list(&ScreeningList::Denylist, &accounts[0]);
+    client.add_to_list(&ScreeningList::Denylist, &accounts[1]);
+    client.add_to_list(&ScreeningList::Denylist, &accounts[2]);
+    assert_eq!(
+        client.list_addresses(&ScreeningList::Denylist, &0, &10),
+        vec![env, accounts[0].clone(), accounts[1].clone(), accounts[2].clone()]
+    );
+    assert_eq!(
+        client.list_addresses(&ScreeningList::Denylist, &1, &1),
+        vec![env, accounts[1].clone()]
+    );
+    assert_eq!(client.list_addresses(&ScreeningList::Allowlist, &0, &10), vec![env]);
+
+    // the last address takes the position of a removed address
+    client.remove_from_list(&ScreeningList::Denylist, &accounts[0]);
+    let event: ListChangedEvent = last_admin_event(env, "remove_from_list");
+    assert_eq!((event.old_listed, event.new_listed), (true, false));
+    assert!(!client.is_listed(&ScreeningList::Denylist, &accounts[0]));
+    assert_eq!(
+        client.list_addresses(&ScreeningList::Denylist, &0, &10),
+        vec![env, accounts[2].clone(), accounts[1].clone()]
+    );
+    client.remove_from_list(&ScreeningList::Denylist, &accounts[1]);
+    client.remove_from_list(&ScreeningList::Denylist, &accounts[1]);
+    assert_eq!(
+        client.list_addresses(&ScreeningList::Denylist, &0, &10),
+        vec![env, accounts[2].clone()]
+    );
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_add_to_list_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.add_to_list(&ScreeningList::Denylist, &setup.funder);
+}
+
+#[test]
+fn test_denylisted_addresses_are_blocked() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let recipient = Address::generate(env);
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+
+    // a denylisted recipient cannot receive a sink
+    client.mock_all_auths().add_to_list(&ScreeningList::Denylist, &recipient);
+    assert_eq!(sink_for(&setup, &recipient), Err(SinkError::AddressBlocked));
+    let check = client.check_sink(&setup.funder, &recipient, &1_000_000, &Symbol::new(env, "VCS1360"));
+    assert_eq!(check.errors, vec![env, SinkError::AddressBlocked as u32]);
+
+    // nor as an item of a batch
+    let item = |recipient: &Address| BatchSinkItem {
+        recipient: recipient.clone(),
+        amount: 1_000_000,
+        project_id: Symbol::new(env, "VCS1360"),
+        memo_text: String::from_str(env, ""),
+    };
+    let items = vec![env, item(&recipient), item(&setup.funder)];
+    let results = client.mock_all_auths().sink_carbon_batch(&setup.funder, &items, &false);
+    assert_eq!(results.get_unchecked(0), BatchSinkResult::Failed(SinkError::AddressBlocked as u32));
+    assert!(matches!(results.get_unchecked(1), BatchSinkResult::Sunk(_)));
+    assert_eq!(carbon_client.balance(&setup.funder), 9_000_000);
+
+    // a denylisted funder cannot sink at all
+    client.mock_all_auths().add_to_list(&ScreeningList::Denylist, &setup.funder);
+    assert_eq!(sink_for(&setup, &setup.funder), Err(SinkError::AddressBlocked));
+    let res = client.mock_all_auths().try_sink_carbon_batch(&setup.funder, &vec![env, item(&setup.funder)], &false);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::AddressBlocked);
+    assert_eq!(carbon_client.balance(&setup.funder), 9_000_000);
+
+    // the denylist takes precedence over the allowlist
+    client.mock_all_auths().add_to_list(&ScreeningList::Allowlist, &setup.funder);
+    assert_eq!(sink_for(&setup, &setup.funder), Err(SinkError::AddressBlocked));
+
+    client.mock_all_auths().remove_from_list(&ScreeningList::Denylist, &setup.funder);
+    assert!(sink_for(&setup, &setup.funder).is_ok());
+}
+
+#[test]
+fn test_allowlist_only() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let recipient = Address::generate(env);
+    assert!(!client.is_allowlist_only());
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_allowlist_only",
+                args: (true,).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_allowlist_only(&true);
+    let event: AllowlistOnlyChangedEvent = last_admin_event(env, "set_allowlist_only");
+    assert_eq!(
+        event,
+        AllowlistOnlyChangedEvent { admin: setup.carbonsink_issuer.clone(), old_enabled: false, new_enabled: true }
+    );
+    assert!(client.is_allowlist_only());
+    assert!(client.get_config().allowlist_only);
+
+    // both the funder and the recipient must be on the allowlist
+    assert_eq!(sink_for(&setup, &recipient), Err(SinkError::AddressBlocked));
+    client.mock_all_auths().add_to_list(&ScreeningList::Allowlist, &setup.funder);
+    assert_eq!(sink_for(&setup, &recipient), Err(SinkError::AddressBlocked));
+    client.mock_all_auths().add_to_list(&ScreeningList::Allowlist, &recipient);
+    assert!(sink_for(&setup, &recipient).is_ok());
+
+    // the allowlist has no effect once allowlist-only mode is disabled
+    client.mock_all_auths().set_allowlist_only(&false);
+    assert!(sink_for(&setup, &Address::generate(env)).is_ok());
+}
+
+#[test]
+fn test_invalid_recipients() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+
+    for recipient in [setup.contract_id.clone(), setup.carbon_sac.address(), setup.carbonsink_sac.address()] {
+        assert_eq!(sink_for(&setup, &recipient), Err(SinkError::InvalidRecipient));
+        let check = client.check_sink(&setup.funder, &recipient, &1_000_000, &Symbol::new(env, "VCS1360"));
+        assert!(check.errors.contains(SinkError::InvalidRecipient as u32));
+    }
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000);
+}
@@ -1,18 +1,22 @@
-use soroban_sdk::{token, vec, Address, Env, IntoVal, String, Symbol};
-use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, String, Symbol, TryFromVal};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::token::TokenClient;
 
+use crate::contract::SinkReceipt;
 use crate::errors::{SACError, SinkError};
+use crate::events::{SinkEventData, SINK_EVENT_VERSION};
+use crate::storage_types::{QuantizationUnit, RoundingPolicy};
 use crate::tests::fixtures::{set_up_contracts_and_funder, set_up_contracts_and_funder_inner};
 use crate::tests::utils::{
     bytes_to_contract, create_account_entry, create_trustline, deploy_native_sac, sink_carbon_with_auth,
     SinkTestData,
 };
-use crate::utils::quantize_to_kg;
+use crate::utils::quantize;
 
 #[test]
 fn test_quantize_to_kg() {
     let tons_with_remainder = 50_000_123_i64;
+    let quantize_to_kg = |amount| quantize(amount, QuantizationUnit::Kilogram, RoundingPolicy::Truncate).unwrap();
     let quantized_tons = quantize_to_kg(tons_with_remainder);
     assert_eq!(quantized_tons, 50_000_000_i64);
 
@@ -42,6 +46,78 @@ fn test_sink_carbon_happy() {
     assert_eq!(carbonsink_client.balance(&setup.funder), 1_000_000);
 }
 
+#[test]
+fn test_sink_carbon_returns_receipt() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    env.ledger().with_mut(|li| {
+        li.sequence_number = 1_234;
+        li.timestamp = 1_700_000_000;
+    });
+
+    // have the funder sink 0.1234567 CARBON
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_234_567_i64,
+        project_id: "VCS1360",
+        memo_text: "123 kg 🌳🌴",
+    };
+    let receipt = sink_carbon_with_auth(&setup, &test_data).unwrap();
+    assert_eq!(
+        receipt,
+        SinkReceipt {
+            retirement_id: 1,
+            requested_amount: 1_234_567,
+            amount: 1_230_000,
+            remainder: 4_567,
+            fee: 0,
+            project_id: Symbol::new(env, "VCS1360"),
+            ledger: 1_234,
+            timestamp: 1_700_000_000,
+        }
+    );
+}
+
+#[test]
+fn test_sink_carbon_publishes_sink_event() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let recipient = Address::generate(env);
+
+    // have the funder sink 0.1234567 CARBON for the recipient
+    let test_data = SinkTestData { 
+        funder: &setup.funder,
+        recipient: &recipient,
+        amount: 1_234_567_i64,
+        project_id: "VCS1360",
+        memo_text: "123 kg 🌳🌴",
+    };
+    let retirement_id = sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id;
+
+    // the sink event is published last, after the SAC events
+    let (contract_id, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(contract_id, setup.contract_id);
+    assert_eq!(
+        topics,
+        (String::from_str(env, "sink_carbon"), String::from_str(env, "sink")).into_val(env)
+    );
+    assert_eq!(
+        SinkEventData::try_from_val(env, &data).unwrap(),
+        SinkEventData {
+            version: SINK_EVENT_VERSION,
+            retirement_id,
+            funder: setup.funder.clone(),
+            recipient,
+            requested_amount: 1_234_567,
+            amount: 1_230_000,
+            fee: 0,
+            project_id: Symbol::new(env, "VCS1360"),
+            memo_text: String::from_str(env, "123 kg 🌳🌴"),
+        }
+    );
+}
+
 #[test]
 fn test_sink_carbon_twice() {
     let setup = set_up_contracts_and_funder(10_000_000, None);
@@ -159,7 +235,7 @@ fn test_sink_carbon_debug_komet() {
         funder: &setup.funder,
         recipient: &recipient,
         amount: amount,
-        project_id: "",
+        project_id: "VCS1360",
         memo_text: "",
     };
     // collect balances before the swap
@@ -208,7 +284,7 @@ fn test_sink_carbon_debug_komet() {
 #[should_panic = "ContractDeactivated"]
 fn test_sink_contract_inactive() {
     let setup = set_up_contracts_and_funder(10_000_000, None);
-    setup.sink_client.mock_all_auths().deactivate();
+    setup.sink_client.mock_all_auths().deactivate(&setup.carbonsink_issuer);
 
     // attempt to sink 1 CARBON
     let test_data = SinkTestData { 
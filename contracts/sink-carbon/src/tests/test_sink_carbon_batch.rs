@@ -0,0 +1,207 @@
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, Env, String, Symbol, Vec};
+
+use crate::contract::{BatchSinkItem, BatchSinkResult, SinkReceipt, MAX_BATCH_SIZE};
+use crate::errors::SinkError;
+use crate::limits::RateLimit;
+use crate::tests::fixtures::set_up_contracts_and_funder;
+
+fn batch_item(env: &Env, recipient: &Address, amount: i64, project_id: &str) -> BatchSinkItem {
+    BatchSinkItem {
+        recipient: recipient.clone(),
+        amount,
+        project_id: Symbol::new(env, project_id),
+        memo_text: String::from_str(env, "batch 🌳🌴"),
+    }
+}
+
+/// The expected result of a sunk item without protocol fee.
+fn sunk(env: &Env, retirement_id: u64, requested_amount: i64, amount: i64, project_id: &str) -> BatchSinkResult {
+    BatchSinkResult::Sunk(SinkReceipt {
+        retirement_id,
+        requested_amount,
+        amount,
+        remainder: requested_amount - amount,
+        fee: 0,
+        project_id: Symbol::new(env, project_id),
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    })
+}
+
+#[test]
+fn test_sink_carbon_batch_happy() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let recipients = [Address::generate(env), Address::generate(env)];
+
+    let items = vec![
+        env,
+        batch_item(env, &recipients[0], 1_000_000, "VCS1360"),
+        batch_item(env, &recipients[1], 2_005_000, "OFP1234567890"),
+    ];
+    let results = client.sink_carbon_batch(&setup.funder, &items, &true);
+    assert_eq!(
+        results,
+        vec![
+            env,
+            sunk(env, 1, 1_000_000, 1_000_000, "VCS1360"),
+            sunk(env, 2, 2_005_000, 2_000_000, "OFP1234567890"),
+        ]
+    );
+
+    // assert the effect on balances
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 7_000_000);
+    assert_eq!(carbonsink_client.balance(&recipients[0]), 1_000_000);
+    assert_eq!(carbonsink_client.balance(&recipients[1]), 2_000_000);
+
+    // every item has its own retirement record
+    let retirement = client.get_retirement(&2);
+    assert_eq!(retirement.recipient, recipients[1]);
+    assert_eq!(retirement.amount, 2_000_000);
+}
+
+#[test]
+fn test_sink_carbon_batch_skips_failing_items() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let recipient = Address::generate(env);
+    let recipient_without_account = Address::from_str(
+        env, "GBDCUWVK2SXI6DA6RD23KDBVIWMJSKLDEN3KZBIMX3TH23OJUR4YSQR4"
+    );
+
+    let items = vec![
+        env,
+        batch_item(env, &recipient, 990_000, "VCS1360"),
+        batch_item(env, &recipient, 2_000_000, "VCS1360"),
+    ];
+    let results = client.sink_carbon_batch(&setup.funder, &items, &false);
+    assert_eq!(
+        results,
+        vec![
+            env,
+            BatchSinkResult::Failed(SinkError::AmountTooLow as u32),
+            sunk(env, 1, 2_000_000, 2_000_000, "VCS1360"),
+        ]
+    );
+
+    // a batch without any sunk item burns nothing
+    let items = vec![
+        env,
+        batch_item(env, &recipient, 1_000_000, "VCS136O"),
+        batch_item(env, &recipient_without_account, 1_000_000, "VCS1360"),
+    ];
+    let results = client.sink_carbon_batch(&setup.funder, &items, &false);
+    assert_eq!(
+        results,
+        vec![
+            env,
+            BatchSinkResult::Failed(SinkError::UnknownProject as u32),
+            BatchSinkResult::Failed(SinkError::AccountOrTrustlineMissing as u32),
+        ]
+    );
+
+    // only the sunk item is burned
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 8_000_000);
+    assert_eq!(carbonsink_client.balance(&recipient), 2_000_000);
+}
+
+#[test]
+fn test_sink_carbon_batch_atomic_failure() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let recipient = Address::generate(env);
+
+    let items = vec![
+        env,
+        batch_item(env, &recipient, 1_000_000, "VCS1360"),
+        batch_item(env, &recipient, 990_000, "VCS1360"),
+    ];
+    let sink_res = client.try_sink_carbon_batch(&setup.funder, &items, &true);
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::AmountTooLow);
+
+    // assert the lack of effect on balances
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000);
+    assert_eq!(carbonsink_client.balance(&recipient), 0);
+    assert_eq!(client.get_totals().sink_count, 0);
+}
+
+#[test]
+fn test_sink_carbon_batch_insufficient_balance() {
+    let setup = set_up_contracts_and_funder(2_500_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let recipient = Address::generate(env);
+
+    // the total of 0.3 t exceeds the funder's balance, even though each item does not
+    let items = vec![
+        env,
+        batch_item(env, &recipient, 1_000_000, "VCS1360"),
+        batch_item(env, &recipient, 2_000_000, "VCS1360"),
+    ];
+    let sink_res = client.try_sink_carbon_batch(&setup.funder, &items, &false);
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::InsufficientBalance);
+
+    // the mints are reverted along with the failed burn
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbonsink_client.balance(&recipient), 0);
+    assert_eq!(client.get_totals().sink_count, 0);
+}
+
+#[test]
+fn test_sink_carbon_batch_size_limits() {
+    let setup = set_up_contracts_and_funder(100_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let recipient = Address::generate(env);
+
+    let empty_res = client.try_sink_carbon_batch(&setup.funder, &Vec::new(env), &true);
+    assert_eq!(empty_res.unwrap_err().unwrap(), SinkError::EmptyBatch);
+
+    let mut items = Vec::new(env);
+    for _ in 0..MAX_BATCH_SIZE {
+        items.push_back(batch_item(env, &recipient, 1_000_000, "VCS1360"));
+    }
+    assert_eq!(client.sink_carbon_batch(&setup.funder, &items, &true).len(), MAX_BATCH_SIZE);
+
+    items.push_back(batch_item(env, &recipient, 1_000_000, "VCS1360"));
+    let too_large_res = client.try_sink_carbon_batch(&setup.funder, &items, &true);
+    assert_eq!(too_large_res.unwrap_err().unwrap(), SinkError::BatchTooLarge);
+}
+
+#[test]
+fn test_sink_carbon_batch_fits_network_limits() {
+    let setup = set_up_contracts_and_funder(100_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+
+    // the most expensive batch: a fee, a rate limit and only new recipients
+    client.set_fee_bps(admin, &100);
+    client.set_treasury(&Address::generate(env));
+    client.set_rate_limit(admin, &Some(RateLimit { window_ledgers: 17_280, max_amount: 1_000_000_000 }));
+    let mut items = Vec::new(env);
+    for _ in 0..MAX_BATCH_SIZE {
+        items.push_back(batch_item(env, &Address::generate(env), 1_000_000, "VCS1360"));
+    }
+    client.sink_carbon_batch(&setup.funder, &items, &true);
+
+    // the per-transaction limits of the network
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= 25);
+    assert!(resources.read_entries + resources.write_entries <= 40);
+    assert!(resources.write_bytes <= 132_096);
+    assert!(resources.read_bytes <= 200_000);
+    assert!(resources.contract_events_size_bytes <= 8_198);
+    assert!(resources.instructions <= 100_000_000);
+}
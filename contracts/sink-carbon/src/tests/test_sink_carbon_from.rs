@@ -0,0 +1,110 @@
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, IntoVal, String, Symbol};
+
+use crate::errors::SinkError;
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+
+fn approve_contract(setup: &Setup, amount: i128) {
+    let carbon_client = TokenClient::new(&setup.env, &setup.carbon_sac.address());
+    carbon_client
+        .mock_all_auths()
+        .approve(&setup.funder, &setup.contract_id, &amount, &1_000);
+}
+
+#[test]
+fn test_sink_carbon_from_happy() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let operator = Address::generate(env);
+    let recipient = Address::generate(env);
+    let project_id = Symbol::new(env, "VCS1360");
+    let memo_text = String::from_str(env, "100 kg 🌳🌴");
+
+    // the funder approves the operator and grants an allowance to the sink contract
+    client.mock_all_auths().set_operator(&setup.funder, &operator, &true);
+    assert!(client.is_operator(&setup.funder, &operator));
+    approve_contract(&setup, 3_000_000);
+
+    // only the operator needs to authorize the sink
+    let amount = 1_000_000_i64;
+    let receipt = client
+        .mock_auths(&[MockAuth {
+            address: &operator,
+            invoke: &MockAuthInvoke {
+                contract: &setup.contract_id,
+                fn_name: "sink_carbon_from",
+                args: (
+                    operator.clone(), setup.funder.clone(), recipient.clone(), amount, project_id.clone(),
+                    memo_text.clone(),
+                ).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .sink_carbon_from(&operator, &setup.funder, &recipient, &amount, &project_id, &memo_text);
+    assert_eq!(receipt.retirement_id, 1);
+
+    // assert the effect on balances and the allowance
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 9_000_000);
+    assert_eq!(carbon_client.allowance(&setup.funder, &setup.contract_id), 2_000_000);
+    assert_eq!(carbonsink_client.balance(&recipient), 1_000_000);
+    assert_eq!(client.get_retirement(&receipt.retirement_id).funder, setup.funder);
+}
+
+#[test]
+fn test_sink_carbon_from_operator_not_approved() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let operator = Address::generate(env);
+    approve_contract(&setup, 3_000_000);
+
+    let sink_res = client.try_sink_carbon_from(
+        &operator, &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::OperatorNotApproved);
+
+    // revoked operators are no longer approved
+    client.set_operator(&setup.funder, &operator, &true);
+    client.set_operator(&setup.funder, &operator, &false);
+    assert!(!client.is_operator(&setup.funder, &operator));
+    let sink_res = client.try_sink_carbon_from(
+        &operator, &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::OperatorNotApproved);
+}
+
+#[test]
+fn test_sink_carbon_from_insufficient_allowance() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let operator = Address::generate(env);
+    client.set_operator(&setup.funder, &operator, &true);
+    approve_contract(&setup, 500_000);
+
+    let sink_res = client.try_sink_carbon_from(
+        &operator, &setup.funder, &setup.funder, &1_000_000,
+        &Symbol::new(env, "VCS1360"), &String::from_str(env, ""),
+    );
+    assert_eq!(sink_res.unwrap_err().unwrap(), SinkError::InsufficientAllowance);
+
+    // assert the lack of effect on balances
+    let carbon_client = TokenClient::new(env, &setup.carbon_sac.address());
+    assert_eq!(carbon_client.balance(&setup.funder), 10_000_000);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_set_operator_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let operator = Address::generate(&setup.env);
+
+    // it should fail because the call lacks the funder's auth
+    setup.sink_client.set_operator(&setup.funder, &operator, &true);
+}
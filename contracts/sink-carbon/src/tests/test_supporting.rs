@@ -1,8 +1,14 @@
 use soroban_sdk::token::StellarAssetClient;
-use soroban_sdk::{Address, IntoVal};
+use soroban_sdk::{Address, IntoVal, String};
 use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
 
+use crate::contract::SinkConfig;
+use crate::errors::SinkError;
+use crate::events::{ActiveChangedEvent, AdminResetEvent, MinimumChangedEvent, SuccessorChangedEvent};
+use crate::migrations::SCHEMA_VERSION;
+use crate::storage_types::{QuantizationUnit, RoundingPolicy, TtlPolicy};
 use crate::tests::fixtures::set_up_contracts_and_funder;
+use crate::tests::utils::last_admin_event;
 
 #[test]
 fn test_get_sink_minimum_default() {
@@ -24,11 +30,11 @@ fn test_set_sink_minimum_as_admin() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "set_minimum_sink_amount",
-                args: (515_000_i64,).into_val(&setup.env),
+                args: (&admin, 515_000_i64).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .set_minimum_sink_amount(&515_000);
+        .set_minimum_sink_amount(&admin, &515_000);
 
     // the minimum should be set succesfully
     let minimum = client.get_minimum_sink_amount();
@@ -36,23 +42,24 @@ fn test_set_sink_minimum_as_admin() {
 }
 
 #[test]
-#[should_panic = "HostError: Error(Auth, InvalidAction)"]
 fn test_set_sink_minimum_unauthorized() {
     let setup = set_up_contracts_and_funder(0, None);
     let client = setup.sink_client;
+    let caller = Address::generate(&setup.env);
 
     // set minimum as a random address should fail
-    client
+    let res = client
         .mock_auths(&[MockAuth {
-            address: &Address::generate(&setup.env),
+            address: &caller,
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "set_minimum_sink_amount",
-                args: (0_i64,).into_val(&setup.env),
+                args: (&caller, 0_i64).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .set_minimum_sink_amount(&0);
+        .try_set_minimum_sink_amount(&caller, &0);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
 }
 
 #[test]
@@ -69,11 +76,11 @@ fn test_set_negative_sink_minimum() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "set_minimum_sink_amount",
-                args: (-1_i64,).into_val(&setup.env),
+                args: (&admin, -1_i64).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .set_minimum_sink_amount(&-1);
+        .set_minimum_sink_amount(&admin, &-1);
 }
 
 #[test]
@@ -93,11 +100,11 @@ fn test_activate_deactivate() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "deactivate",
-                args: ().into_val(&setup.env),
+                args: (&admin,).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .deactivate();
+        .deactivate(&admin);
 
     let after_deactivate = client.is_active();
     assert_eq!(after_deactivate, false);
@@ -126,7 +133,7 @@ fn test_deactivate_unauthorized() {
     let client = setup.sink_client;
 
     // it should fail because the call lacks admin auth
-    client.deactivate();
+    client.deactivate(&setup.carbonsink_issuer);
 }
 
 #[test]
@@ -149,11 +156,11 @@ fn test_reset_admin() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "reset_admin",
-                args: ().into_val(&setup.env),
+                args: (&admin,).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .reset_admin();
+        .reset_admin(&admin);
 
     assert_eq!(carbonsink_issuer, admin);
     let new_sac_admin = carbonsink_client.admin();
@@ -176,7 +183,7 @@ fn test_set_successor_unauthorized() {
     let client = setup.sink_client;
 
     // it should fail because the call lacks admin auth
-    client.set_contract_successor(&client.address);
+    client.set_contract_successor(&setup.carbonsink_issuer, &client.address);
 }
 
 #[test]
@@ -193,13 +200,125 @@ fn test_set_and_get_successor() {
             invoke: &MockAuthInvoke {
                 contract: &client.address,
                 fn_name: "set_contract_successor",
-                args: (&new_contract,).into_val(&setup.env),
+                args: (&admin, &new_contract).into_val(&setup.env),
                 sub_invokes: &[],
             },
         }])
-        .set_contract_successor(&new_contract);
+        .set_contract_successor(&admin, &new_contract);
 
     let successor: Address = client.get_contract_successor();
     assert_eq!(successor, new_contract);
     
 }
+
+#[test]
+fn test_admin_events() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let admin = setup.carbonsink_issuer;
+    let new_contract = Address::generate(env);
+
+    client.set_minimum_sink_amount(&admin, &515_000);
+    let event: MinimumChangedEvent = last_admin_event(env, "set_minimum_sink_amount");
+    assert_eq!(
+        event,
+        MinimumChangedEvent { admin: admin.clone(), old_minimum: 1_000_000, new_minimum: 515_000 }
+    );
+
+    client.set_contract_successor(&admin, &new_contract);
+    let event: SuccessorChangedEvent = last_admin_event(env, "set_contract_successor");
+    assert_eq!(
+        event,
+        SuccessorChangedEvent {
+            admin: admin.clone(),
+            old_successor: client.address.clone(),
+            new_successor: new_contract,
+        }
+    );
+
+    client.deactivate(&admin);
+    let event: ActiveChangedEvent = last_admin_event(env, "deactivate");
+    assert_eq!(
+        event,
+        ActiveChangedEvent { admin: admin.clone(), old_is_active: true, new_is_active: false }
+    );
+
+    client.activate();
+    let event: ActiveChangedEvent = last_admin_event(env, "activate");
+    assert_eq!(
+        event,
+        ActiveChangedEvent { admin: admin.clone(), old_is_active: false, new_is_active: true }
+    );
+
+    client.reset_admin(&admin);
+    let event: AdminResetEvent = last_admin_event(env, "reset_admin");
+    assert_eq!(
+        event,
+        AdminResetEvent {
+            admin: admin.clone(),
+            old_sac_admin: client.address.clone(),
+            new_sac_admin: admin,
+            old_is_active: true,
+            new_is_active: false,
+        }
+    );
+}
+
+#[test]
+fn test_get_config() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+
+    let config = client.get_config();
+    assert_eq!(
+        config,
+        SinkConfig {
+            contract_version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+            schema_version: SCHEMA_VERSION,
+            admin: admin.clone(),
+            pending_admin: None,
+            carbon_id: setup.carbon_sac.address(),
+            carbonsink_id: setup.carbonsink_sac.address(),
+            is_active: true,
+            sink_minimum: 1_000_000,
+            sink_maximum: None,
+            rate_limit_window_ledgers: 0,
+            rate_limit_max_amount: 0,
+            allowlist_only: false,
+            successor: client.address.clone(),
+            forwarding: false,
+            fee_bps: 0,
+            treasury: admin.clone(),
+            quantization_unit: QuantizationUnit::Kilogram,
+            rounding_policy: RoundingPolicy::Truncate,
+            timelock_delay: 0,
+            ttl_policy: TtlPolicy {
+                instance_threshold: 29 * 17280,
+                instance_extend_amount: 30 * 17280,
+                persistent_threshold: 150 * 17280,
+                persistent_extend_amount: 180 * 17280,
+            },
+        }
+    );
+
+    // the config reflects changed settings
+    let new_admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    let successor = Address::generate(env);
+    let client = client.mock_all_auths();
+    client.propose_admin(&new_admin);
+    client.set_treasury(&treasury);
+    client.set_fee_bps(admin, &25);
+    client.set_contract_successor(admin, &successor);
+    client.deactivate(admin);
+
+    let config = client.get_config();
+    assert_eq!(config.pending_admin, Some(new_admin));
+    assert_eq!(config.treasury, treasury);
+    assert_eq!(config.fee_bps, 25);
+    assert_eq!(config.successor, successor);
+    assert!(!config.is_active);
+}
@@ -0,0 +1,253 @@
+use soroban_sdk::{Address, BytesN, IntoVal};
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::StellarAssetClient;
+
+use crate::errors::SinkError;
+use crate::events::{ScheduledOpEvent, TimelockDelayChangedEvent};
+use crate::roles::Role;
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+use crate::timelock::{AdminOp, ScheduledOp, MAX_TIMELOCK_DELAY};
+
+const DELAY: u32 = 100;
+
+/// Sets up the contracts with a timelock delay of `DELAY` ledgers.
+fn set_up_with_timelock<'a>() -> Setup<'a> {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_timelock_delay",
+                args: (DELAY,).into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_timelock_delay(&DELAY);
+    setup
+}
+
+fn advance_ledgers(setup: &Setup, ledgers: u32) {
+    setup.env.ledger().with_mut(|li| li.sequence_number += ledgers);
+}
+
+#[test]
+fn test_set_timelock_delay() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    assert_eq!(client.get_timelock_delay(), 0);
+
+    let setup = set_up_with_timelock();
+    let client = &setup.sink_client;
+    let event: TimelockDelayChangedEvent = last_admin_event(&setup.env, "set_timelock_delay");
+    assert_eq!(
+        event,
+        TimelockDelayChangedEvent { admin: setup.carbonsink_issuer.clone(), old_delay: 0, new_delay: DELAY }
+    );
+    assert_eq!(client.get_timelock_delay(), DELAY);
+}
+
+#[test]
+fn test_set_timelock_delay_too_long() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let res = setup.sink_client.mock_all_auths().try_set_timelock_delay(&(MAX_TIMELOCK_DELAY + 1));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::TimelockDelayTooLong);
+}
+
+#[test]
+fn test_sensitive_ops_are_timelocked() {
+    let setup = set_up_with_timelock();
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+
+    let res = client.try_set_contract_successor(admin, &Address::generate(&setup.env));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_reset_admin(admin);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_set_minimum_sink_amount(admin, &0);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_set_fee_bps(admin, &50);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_set_timelock_delay(&0);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_upgrade(&BytesN::from_array(&setup.env, &[0; 32]));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+    let res = client.try_set_treasury(&Address::generate(&setup.env));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationTimelocked);
+
+    // deactivate remains immediate
+    client.deactivate(admin);
+    assert!(!client.is_active());
+}
+
+#[test]
+fn test_schedule_and_execute_op() {
+    let setup = set_up_with_timelock();
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let manager = Address::generate(env);
+    client.mock_all_auths().grant_role(&Role::ConfigManager, &manager);
+
+    // a ConfigManager schedules a fee change
+    let op = AdminOp::SetFeeBps(50);
+    let op_id = client
+        .mock_auths(&[MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "schedule_op",
+                args: (&manager, op.clone()).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .schedule_op(&manager, &op);
+    assert_eq!(op_id, 1);
+    let ready_ledger = env.ledger().sequence() + DELAY;
+    let event: ScheduledOpEvent = last_admin_event(env, "schedule_op");
+    assert_eq!(
+        event,
+        ScheduledOpEvent { admin: manager.clone(), op_id, op: op.clone(), ready_ledger }
+    );
+    let scheduled = client.get_scheduled_op(&op_id);
+    assert_eq!(scheduled, ScheduledOp { id: op_id, op: op.clone(), ready_ledger });
+
+    // it cannot be executed before the delay has elapsed
+    advance_ledgers(&setup, DELAY - 1);
+    let res = client.mock_all_auths().try_execute_op(&manager, &op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationNotReady);
+    assert_eq!(client.get_fee_bps(), 0);
+
+    advance_ledgers(&setup, 1);
+    client
+        .mock_auths(&[MockAuth {
+            address: &manager,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "execute_op",
+                args: (&manager, op_id).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .execute_op(&manager, &op_id);
+    let event: ScheduledOpEvent = last_admin_event(env, "execute_op");
+    assert_eq!(event, ScheduledOpEvent { admin: manager.clone(), op_id, op, ready_ledger });
+    assert_eq!(client.get_fee_bps(), 50);
+
+    // an operation can only be executed once
+    let res = client.mock_all_auths().try_execute_op(&manager, &op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationNotFound);
+    let res = client.try_get_scheduled_op(&op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationNotFound);
+}
+
+#[test]
+fn test_execute_reset_admin() {
+    let setup = set_up_with_timelock();
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+    let carbonsink_client = StellarAssetClient::new(&setup.env, &setup.carbonsink_sac.address());
+
+    let op_id = client.schedule_op(admin, &AdminOp::ResetAdmin);
+    assert_eq!(carbonsink_client.admin(), setup.contract_id);
+
+    advance_ledgers(&setup, DELAY);
+    client.execute_op(admin, &op_id);
+    assert_eq!(carbonsink_client.admin(), *admin);
+    assert!(!client.is_active());
+}
+
+#[test]
+fn test_execute_set_treasury() {
+    let setup = set_up_with_timelock();
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+    let treasury = Address::generate(env);
+
+    let op_id = client.schedule_op(admin, &AdminOp::SetTreasury(treasury.clone()));
+    advance_ledgers(&setup, DELAY);
+    client.execute_op(admin, &op_id);
+    assert_eq!(client.get_treasury(), treasury);
+}
+
+#[test]
+fn test_execute_timelock_delay_change() {
+    let setup = set_up_with_timelock();
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+
+    // lifting the timelock is itself timelocked
+    let op_id = client.schedule_op(admin, &AdminOp::SetTimelockDelay(0));
+    advance_ledgers(&setup, DELAY);
+    client.execute_op(admin, &op_id);
+    assert_eq!(client.get_timelock_delay(), 0);
+
+    // sensitive operations take effect immediately again
+    client.set_fee_bps(admin, &50);
+    assert_eq!(client.get_fee_bps(), 50);
+}
+
+#[test]
+fn test_cancel_op() {
+    let setup = set_up_with_timelock();
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+    let successor = Address::generate(env);
+
+    let op = AdminOp::SetContractSuccessor(successor);
+    let op_id = client.schedule_op(admin, &op);
+    client.cancel_op(admin, &op_id);
+    let event: ScheduledOpEvent = last_admin_event(env, "cancel_op");
+    assert_eq!(
+        event,
+        ScheduledOpEvent { admin: admin.clone(), op_id, op, ready_ledger: env.ledger().sequence() + DELAY }
+    );
+
+    // a cancelled operation cannot be executed
+    advance_ledgers(&setup, DELAY);
+    let res = client.try_execute_op(admin, &op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::OperationNotFound);
+    assert_eq!(client.get_contract_successor(), client.address);
+}
+
+#[test]
+fn test_schedule_op_unauthorized() {
+    let setup = set_up_with_timelock();
+    let env = &setup.env;
+    let client = setup.sink_client.mock_all_auths();
+    let manager = Address::generate(env);
+    client.grant_role(&Role::ConfigManager, &manager);
+
+    // a ConfigManager can neither reset the admin nor change the timelock delay
+    let res = client.try_schedule_op(&manager, &AdminOp::ResetAdmin);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+    let res = client.try_schedule_op(&manager, &AdminOp::SetTimelockDelay(0));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+    let res = client.try_schedule_op(&manager, &AdminOp::Upgrade(BytesN::from_array(env, &[0; 32])));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+
+    // nor execute or cancel an operation of another role
+    let op_id = client.schedule_op(&setup.carbonsink_issuer, &AdminOp::ResetAdmin);
+    let res = client.try_cancel_op(&manager, &op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+    advance_ledgers(&setup, DELAY);
+    let res = client.try_execute_op(&manager, &op_id);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::Unauthorized);
+}
+
+#[test]
+fn test_schedule_invalid_op() {
+    let setup = set_up_with_timelock();
+    let client = setup.sink_client.mock_all_auths();
+    let admin = &setup.carbonsink_issuer;
+
+    let res = client.try_schedule_op(admin, &AdminOp::SetMinimumSinkAmount(-1));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::NegativeAmount);
+    let res = client.try_schedule_op(admin, &AdminOp::SetFeeBps(1_001));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::FeeTooHigh);
+    let res = client.try_schedule_op(admin, &AdminOp::SetTimelockDelay(MAX_TIMELOCK_DELAY + 1));
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::TimelockDelayTooLong);
+}
@@ -1,9 +1,18 @@
 use soroban_env_host::HostError;
-use soroban_sdk::testutils::{storage::Instance, Deployer, Ledger};
+use soroban_sdk::testutils::{storage::{Instance, Persistent}, Deployer, Ledger, MockAuth, MockAuthInvoke};
 use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, IntoVal, String, Symbol};
 
+use crate::errors::SinkError;
+use crate::roles::Role;
+use crate::screening::ScreeningList;
+use crate::timelock::AdminOp;
+use crate::ttl::MAX_TTL_PAGE_SIZE;
+use crate::events::TtlPolicyChangedEvent;
+use crate::storage_types::{DataKey, TtlPolicy};
 use crate::tests::fixtures::set_up_contracts_with_ttl_settings;
-use crate::tests::utils::{sink_carbon_with_auth, SinkTestData};
+use crate::tests::utils::{last_admin_event, sink_carbon_with_auth, SinkTestData};
 
 const DAY_IN_LEDGERS: u32 = 17280;
 
@@ -14,11 +23,9 @@ fn test_extend_ttl_sink_carbon() {
 
     // Check initial ledger/network settings
     env.as_contract(&setup.contract_id, || {
-        // Note, that TTL doesn't include the current ledger, but when entry
-        // is created the current ledger is counted towards the number of
-        // ledgers specified by `min_persistent/temp_entry_ttl`, thus
-        // the TTL is 1 ledger less than the respective setting.
-        assert_eq!(35_000, env.storage().instance().get_ttl());
+        // Note, that the setup registers the test projects, which already extends
+        // the instance TTL beyond the `min_persistent_entry_ttl` of a new entry.
+        assert_eq!(30 * DAY_IN_LEDGERS, env.storage().instance().get_ttl());
         // TODO: extend with the other storage types if they are used in the future
         // assert_eq!(env.storage().persistent().get_ttl(&DataKey::MyKey), 499);
         // assert_eq!(env.storage().temporary().get_ttl(&DataKey::MyKey), 99);
@@ -60,9 +67,8 @@ fn test_extend_ttl_is_active() {
     let env = setup.env.clone();
     let client = setup.sink_client;
 
-    // check the contract code & instance TTL in this test
-    assert_eq!(35_000, env.deployer().get_contract_code_ttl(&setup.contract_id));
-    assert_eq!(35_000, env.deployer().get_contract_instance_ttl(&setup.contract_id));
+    // check the contract code & instance TTL in this test;
+    // the setup has already extended them by registering the test projects
     assert_eq!(true, client.is_active());
 
     // expected TTL is 30 days
@@ -88,14 +94,15 @@ fn test_contract_archival() {
     let env = setup.env.clone();
     let client = setup.sink_client;
 
-    assert_eq!(35_000, env.deployer().get_contract_code_ttl(&setup.contract_id));
+    // the setup has already extended the TTL by registering the test projects
+    assert_eq!(30 * DAY_IN_LEDGERS, env.deployer().get_contract_code_ttl(&setup.contract_id));
 
     // advance the ledger by 2 days
     const TWO_DAYS: u32 = 2 * DAY_IN_LEDGERS;
     env.ledger().with_mut(|li| {
         li.sequence_number += TWO_DAYS;
     });
-    assert_eq!(35_000 - TWO_DAYS, env.deployer().get_contract_code_ttl(&setup.contract_id));
+    assert_eq!(30 * DAY_IN_LEDGERS - TWO_DAYS, env.deployer().get_contract_code_ttl(&setup.contract_id));
 
     // extend TTL with get_minimum_sink_amount
     assert!(client.get_minimum_sink_amount() > 0);
@@ -109,3 +116,276 @@ fn test_contract_archival() {
     let ttl_res = env.host().get_contract_code_live_until_ledger(setup.contract_id.to_object());
     assert!(HostError::result_matches_err(ttl_res, (ScErrorType::Storage, ScErrorCode::InternalError)))
 }
+
+#[test]
+fn test_extend_ttl_retirement_record() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+
+    let test_data = SinkTestData { 
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let retirement_id = sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id;
+
+    // expected TTL of the retirement record is 180 days
+    env.as_contract(&setup.contract_id, || {
+        let key = DataKey::Retirement(retirement_id);
+        assert_eq!(180 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+    });
+}
+
+#[test]
+fn test_list_retirements_extends_index() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+    let client = &setup.sink_client;
+
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    assert!(sink_carbon_with_auth(&setup, &test_data).is_ok());
+
+    // advance the ledger by 58 days, keeping the instance alive,
+    // so that the index entries drop below the persistent threshold
+    for _ in 0..2 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 29 * DAY_IN_LEDGERS;
+        });
+        assert!(client.is_active());
+    }
+
+    // listing the retirements extends the index entries they are read from
+    assert_eq!(client.list_retirements_by_recipient(&setup.funder, &0, &10).len(), 1);
+    assert_eq!(client.list_retirements_by_funder(&setup.funder, &0, &10).len(), 1);
+    env.as_contract(&setup.contract_id, || {
+        for key in [
+            DataKey::RecipientRetirementCount(setup.funder.clone()),
+            DataKey::RecipientRetirement(setup.funder.clone(), 0),
+            DataKey::FunderRetirementCount(setup.funder.clone()),
+            DataKey::FunderRetirement(setup.funder.clone(), 0),
+            DataKey::Retirement(1),
+        ] {
+            assert_eq!(180 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+        }
+    });
+}
+
+#[test]
+fn test_set_ttl_policy() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+    let client = &setup.sink_client;
+    let old_policy = client.get_ttl_policy();
+
+    let policy = TtlPolicy {
+        instance_threshold: 50 * DAY_IN_LEDGERS,
+        instance_extend_amount: 60 * DAY_IN_LEDGERS,
+        persistent_threshold: 270 * DAY_IN_LEDGERS,
+        persistent_extend_amount: 300 * DAY_IN_LEDGERS,
+    };
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_ttl_policy",
+                args: (policy.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_ttl_policy(&policy);
+    let event: TtlPolicyChangedEvent = last_admin_event(&env, "set_ttl_policy");
+    assert_eq!(
+        event,
+        TtlPolicyChangedEvent {
+            admin: setup.carbonsink_issuer.clone(),
+            old_policy,
+            new_policy: policy.clone(),
+        }
+    );
+    assert_eq!(client.get_ttl_policy(), policy);
+    assert_eq!(client.get_config().ttl_policy, policy);
+
+    // the instance is extended with the new policy right away
+    assert_eq!(60 * DAY_IN_LEDGERS, env.deployer().get_contract_instance_ttl(&setup.contract_id));
+
+    // new retirement records are extended with the new policy
+    let test_data = SinkTestData {
+        funder: &setup.funder,
+        recipient: &setup.funder,
+        amount: 1_000_000_i64,
+        project_id: "VCS1360",
+        memo_text: "100 kg 🌳🌴",
+    };
+    let retirement_id = sink_carbon_with_auth(&setup, &test_data).unwrap().retirement_id;
+    env.as_contract(&setup.contract_id, || {
+        let key = DataKey::Retirement(retirement_id);
+        assert_eq!(300 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+    });
+}
+
+#[test]
+fn test_set_ttl_policy_invalid() {
+    let setup = set_up_contracts_with_ttl_settings(60 * DAY_IN_LEDGERS);
+    let client = setup.sink_client.mock_all_auths();
+    let valid_policy = client.get_ttl_policy();
+
+    // a threshold above its extension amount
+    let policy = TtlPolicy { instance_threshold: 31 * DAY_IN_LEDGERS, ..valid_policy.clone() };
+    let res = client.try_set_ttl_policy(&policy);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::InvalidTtlPolicy);
+
+    // an extension amount below one day
+    let policy = TtlPolicy { persistent_threshold: 0, persistent_extend_amount: 100, ..valid_policy.clone() };
+    let res = client.try_set_ttl_policy(&policy);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::InvalidTtlPolicy);
+
+    // an extension amount beyond the maximum TTL of the network
+    let policy = TtlPolicy { instance_extend_amount: 61 * DAY_IN_LEDGERS, ..valid_policy.clone() };
+    let res = client.try_set_ttl_policy(&policy);
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::InvalidTtlPolicy);
+
+    assert_eq!(client.get_ttl_policy(), valid_policy);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_set_ttl_policy_unauthorized() {
+    let setup = set_up_contracts_with_ttl_settings(60 * DAY_IN_LEDGERS);
+    let policy = setup.sink_client.get_ttl_policy();
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.set_ttl_policy(&policy);
+}
+
+#[test]
+fn test_extend_ttl_prevents_archival() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+    let client = &setup.sink_client;
+
+    // a keeper extends the TTL without any authorization, from the deployment on every 29 days
+    assert_eq!(client.extend_ttl(&0, &MAX_TTL_PAGE_SIZE), None);
+    for _ in 0..2 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 29 * DAY_IN_LEDGERS;
+        });
+        assert_eq!(client.extend_ttl(&0, &MAX_TTL_PAGE_SIZE), None);
+        assert_eq!(30 * DAY_IN_LEDGERS, env.deployer().get_contract_code_ttl(&setup.contract_id));
+        assert_eq!(30 * DAY_IN_LEDGERS, env.deployer().get_contract_instance_ttl(&setup.contract_id));
+    }
+
+    // the project registry has dropped below the persistent threshold, so it has been extended as well
+    env.as_contract(&setup.contract_id, || {
+        let project_id = Symbol::new(&env, "VCS1360");
+        for key in [
+            DataKey::ProjectList,
+            DataKey::Project(project_id.clone()),
+            DataKey::ProjectInventory(project_id),
+        ] {
+            assert_eq!(180 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+        }
+    });
+    assert!(client.is_active());
+}
+
+#[test]
+fn test_extend_ttl_asset_contracts() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+    let asset_ids = [setup.carbon_sac.address(), setup.carbonsink_sac.address()];
+
+    // the mock auth contracts of `sink_carbon_with_auth` would be archived between the sinks
+    let sink = || setup.sink_client.mock_all_auths().try_sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000, &Symbol::new(&env, "VCS1360"), &String::from_str(&env, "100 kg")
+    );
+    assert!(sink().is_ok());
+
+    // sink every 20 days for 100 days, far beyond the few days that the SACs extend their own instances by
+    for _ in 0..5 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 20 * DAY_IN_LEDGERS;
+        });
+        assert!(sink().is_ok());
+
+        // both asset contracts are extended along with this contract
+        for asset_id in &asset_ids {
+            assert_eq!(30 * DAY_IN_LEDGERS, env.deployer().get_contract_instance_ttl(asset_id));
+        }
+    }
+
+    // a keeper keeps the asset contracts alive without sinks
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 20 * DAY_IN_LEDGERS;
+    });
+    assert_eq!(setup.sink_client.extend_ttl(&0, &MAX_TTL_PAGE_SIZE), None);
+    for asset_id in &asset_ids {
+        assert_eq!(30 * DAY_IN_LEDGERS, env.deployer().get_contract_instance_ttl(asset_id));
+    }
+}
+
+#[test]
+fn test_extend_ttl_pages() {
+    let setup = set_up_contracts_with_ttl_settings(360 * DAY_IN_LEDGERS);
+    let env = setup.env.clone();
+    let client = setup.sink_client.mock_all_auths();
+    let account = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    // one item in each section besides the four projects of the registry
+    client.grant_role(&Role::Pauser, &account);
+    client.set_operator(&setup.funder, &operator, &true);
+    client.add_to_list(&ScreeningList::Denylist, &account);
+    let op_id = client.schedule_op(&setup.carbonsink_issuer, &AdminOp::ResetAdmin);
+    let receipt = client.sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000, &Symbol::new(&env, "VCS1360"), &String::from_str(&env, "100 kg")
+    );
+
+    // a keeper extends only the instances with empty pages, until the entries drop below the persistent threshold
+    assert_eq!(client.extend_ttl(&0, &0), Some(0));
+    for _ in 0..2 {
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 29 * DAY_IN_LEDGERS;
+        });
+        assert_eq!(client.extend_ttl(&0, &0), Some(0));
+    }
+    env.as_contract(&setup.contract_id, || {
+        let key = DataKey::Role(Role::Pauser, account.clone());
+        assert_eq!(122 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+    });
+
+    // the pages continue across sections, and the limit is capped
+    assert_eq!(client.extend_ttl(&0, &3), Some(3));
+    assert_eq!(client.extend_ttl(&3, &3), Some(6));
+    assert_eq!(client.extend_ttl(&6, &3), None);
+    assert_eq!(client.extend_ttl(&0, &u32::MAX), None);
+
+    env.as_contract(&setup.contract_id, || {
+        for key in [
+            DataKey::Project(Symbol::new(&env, "max_impact")),
+            DataKey::Role(Role::Pauser, account.clone()),
+            DataKey::RoleEntry(Role::Pauser, 0),
+            DataKey::RoleCount(Role::Pauser),
+            DataKey::Operator(setup.funder.clone(), operator.clone()),
+            DataKey::OperatorEntry(0),
+            DataKey::OperatorCount,
+            DataKey::Listed(ScreeningList::Denylist, account.clone()),
+            DataKey::ListEntry(ScreeningList::Denylist, 0),
+            DataKey::ListCount(ScreeningList::Denylist),
+            DataKey::ScheduledOp(op_id),
+            DataKey::Retirement(receipt.retirement_id),
+            DataKey::RecipientTotal(setup.funder.clone()),
+            DataKey::FunderTotal(setup.funder.clone()),
+        ] {
+            assert_eq!(180 * DAY_IN_LEDGERS, env.storage().persistent().get_ttl(&key));
+        }
+    });
+}
@@ -0,0 +1,228 @@
+use soroban_sdk::{BytesN, IntoVal, String, Symbol};
+use soroban_sdk::testutils::{Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+
+use crate::errors::SinkError;
+use crate::events::{MigrateEvent, UpgradeEvent};
+use crate::migrations::{LEGACY_SCHEMA_VERSION, SCHEMA_VERSION};
+use crate::storage_types::{DataKey, QuantizationUnit, RoundingPolicy};
+use crate::tests::fixtures::{set_up_contracts_and_funder, Setup};
+use crate::tests::utils::last_admin_event;
+
+/// The 0.4.5 release, which predates the project registry, schema versioning and `upgrade`.
+mod legacy_contract {
+    soroban_sdk::contractimport!(file = "src/tests/wasm/sink_carbon_0_4_5.wasm");
+}
+
+/// The current contract, as built by `make build`.
+mod current_contract {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/sink_carbon.wasm");
+}
+
+fn migrate_as_admin(setup: &Setup) -> u32 {
+    let client = &setup.sink_client;
+    client
+        .mock_auths(&[MockAuth {
+            address: &setup.carbonsink_issuer,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "migrate",
+                args: ().into_val(&setup.env),
+                sub_invokes: &[],
+            },
+        }])
+        .migrate()
+}
+
+/// Deploys the 0.4.5 release with the admin and assets of `setup`, and makes it the CarbonSINK SAC admin.
+fn deploy_legacy_contract<'a>(setup: &Setup) -> legacy_contract::Client<'a> {
+    let env = &setup.env;
+    let contract_id = env.register(
+        legacy_contract::WASM,
+        (&setup.carbonsink_issuer, &setup.carbon_sac.address(), &setup.carbonsink_sac.address()),
+    );
+    StellarAssetClient::new(env, &setup.carbonsink_sac.address()).mock_all_auths().set_admin(&contract_id);
+    legacy_contract::Client::new(env, &contract_id)
+}
+
+#[test]
+fn test_schema_version_of_new_contract() {
+    let setup = set_up_contracts_and_funder(0, None);
+    assert_eq!(setup.sink_client.get_schema_version(), SCHEMA_VERSION);
+
+    // migrating an up-to-date contract does nothing
+    assert_eq!(migrate_as_admin(&setup), SCHEMA_VERSION);
+    assert!(setup.env.events().all().is_empty());
+}
+
+#[test]
+fn test_upgrade() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+    let new_wasm_hash = env.deployer().upload_contract_wasm(current_contract::WASM);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+    let event: UpgradeEvent = last_admin_event(env, "upgrade");
+    assert_eq!(
+        event,
+        UpgradeEvent { admin: admin.clone(), new_wasm_hash, schema_version: SCHEMA_VERSION }
+    );
+
+    // the contract now runs the uploaded WASM at the same address, with its storage intact
+    let client = current_contract::Client::new(env, &setup.contract_id);
+    assert_eq!(client.mock_all_auths().migrate(), SCHEMA_VERSION);
+    assert_eq!(client.get_admin(), *admin);
+    assert_eq!(client.get_project_inventory(&Symbol::new(env, "VCS1360")), i64::MAX as i128);
+    let receipt = client.mock_all_auths().sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000, &Symbol::new(env, "VCS1360"), &String::from_str(env, "")
+    );
+    assert_eq!(receipt.retirement_id, 1);
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+    assert_eq!(carbonsink_client.balance(&setup.funder), 1_000_000);
+}
+
+#[test]
+fn test_upgrade_from_legacy_contract() {
+    let setup = set_up_contracts_and_funder(10_000_000, None);
+    let env = &setup.env;
+    let admin = &setup.carbonsink_issuer;
+    let project_id = Symbol::new(env, "VCS1360");
+    let carbonsink_client = TokenClient::new(env, &setup.carbonsink_sac.address());
+
+    // a 0.4.5 contract that has been in use
+    let legacy_client = deploy_legacy_contract(&setup);
+    legacy_client.mock_all_auths().sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000, &project_id, &String::from_str(env, "")
+    );
+    assert_eq!(carbonsink_client.balance(&setup.funder), 1_000_000);
+
+    // 0.4.x has no `upgrade` function, so the test replaces its code with the current WASM directly
+    let contract_id = legacy_client.address.clone();
+    let new_wasm_hash = env.deployer().upload_contract_wasm(current_contract::WASM);
+    env.as_contract(&contract_id, || env.deployer().update_current_contract_wasm(new_wasm_hash));
+    let client = current_contract::Client::new(env, &contract_id);
+    assert_eq!(client.get_schema_version(), LEGACY_SCHEMA_VERSION);
+
+    // settings that were added since 0.4.x fall back to their defaults before migration
+    assert_eq!(client.get_fee_bps(), 0);
+    assert_eq!(client.get_quantization_unit(), current_contract::QuantizationUnit::Kilogram);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "migrate",
+                args: ().into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .migrate();
+    let event: MigrateEvent = last_admin_event(env, "migrate");
+    assert_eq!(
+        event,
+        MigrateEvent {
+            admin: admin.clone(),
+            old_schema_version: LEGACY_SCHEMA_VERSION,
+            new_schema_version: SCHEMA_VERSION,
+        }
+    );
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    // the settings of the 0.4.x contract are kept, and the defaults are now stored explicitly
+    assert_eq!(client.get_admin(), *admin);
+    assert_eq!(client.get_minimum_sink_amount(), 1_000_000);
+    assert!(client.is_active());
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        assert_eq!(instance.get(&DataKey::FeeBps), Some(0_u32));
+        assert_eq!(instance.get(&DataKey::QuantizationUnit), Some(QuantizationUnit::Kilogram));
+        assert_eq!(instance.get(&DataKey::RoundingPolicy), Some(RoundingPolicy::Truncate));
+    });
+
+    // a second migration is a no-op
+    assert_eq!(client.mock_all_auths().migrate(), SCHEMA_VERSION);
+    assert!(env.events().all().is_empty());
+
+    // 0.4.x had no project registry, so sinks are rejected until the admin lists projects with inventory
+    assert_eq!(client.list_projects(&0, &10).len(), 0);
+    let sink = || client.mock_all_auths().try_sink_carbon(
+        &setup.funder, &setup.funder, &1_000_000, &project_id, &String::from_str(env, "")
+    );
+    assert_eq!(sink().unwrap_err().unwrap(), current_contract::SinkError::UnknownProject);
+    let info = current_contract::ProjectInfo {
+        registry: String::from_str(env, "Verra"),
+        methodology: String::from_str(env, "VM0007"),
+        vintage: 2023,
+        country: String::from_str(env, "KE"),
+        metadata_uri: String::from_str(env, ""),
+    };
+    client.mock_all_auths().add_project(&project_id, &info);
+    assert_eq!(sink().unwrap_err().unwrap(), current_contract::SinkError::ProjectInventoryExhausted);
+    client.mock_all_auths().add_project_inventory(&project_id, &1_000_000);
+
+    // retirement records start with the first sink after the upgrade
+    assert_eq!(sink().unwrap().unwrap().retirement_id, 1);
+    assert_eq!(carbonsink_client.balance(&setup.funder), 2_000_000);
+}
+
+#[test]
+fn test_migrate_keeps_settings() {
+    let setup = set_up_contracts_and_funder(0, None);
+    let client = &setup.sink_client;
+    let admin = &setup.carbonsink_issuer;
+    client.mock_all_auths().set_fee_bps(admin, &100);
+    client.mock_all_auths().set_quantization(admin, &QuantizationUnit::Tonne, &RoundingPolicy::Strict);
+
+    // a contract that has changed settings, but predates schema versioning
+    setup.env.as_contract(&setup.contract_id, || {
+        setup.env.storage().instance().remove(&DataKey::SchemaVersion);
+    });
+    assert_eq!(migrate_as_admin(&setup), SCHEMA_VERSION);
+
+    // the migration does not overwrite settings with their defaults
+    assert_eq!(client.get_fee_bps(), 100);
+    assert_eq!(client.get_quantization_unit(), QuantizationUnit::Tonne);
+    assert_eq!(client.get_rounding_policy(), RoundingPolicy::Strict);
+}
+
+#[test]
+fn test_migrate_unsupported_schema_version() {
+    let setup = set_up_contracts_and_funder(0, None);
+    setup.env.as_contract(&setup.contract_id, || {
+        setup.env.storage().instance().set(&DataKey::SchemaVersion, &(SCHEMA_VERSION + 1));
+    });
+
+    let res = setup.sink_client.mock_all_auths().try_migrate();
+    assert_eq!(res.unwrap_err().unwrap(), SinkError::UnsupportedSchemaVersion);
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_migrate_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.migrate();
+}
+
+#[test]
+#[should_panic = "HostError: Error(Auth, InvalidAction)"]
+fn test_upgrade_unauthorized() {
+    let setup = set_up_contracts_and_funder(0, None);
+
+    // it should fail because the call lacks admin auth
+    setup.sink_client.upgrade(&BytesN::from_array(&setup.env, &[0; 32]));
+}
@@ -4,13 +4,15 @@ use std::rc::Rc;
 use soroban_env_host::{budget::AsBudget, Env as _, EnvBase};
 use soroban_sdk::xdr;
 use soroban_sdk::{
-    testutils::{MockAuth, MockAuthInvoke, StellarAssetIssuer}, 
+    testutils::{Events, MockAuth, MockAuthInvoke, StellarAssetIssuer}, 
     xdr::{Asset, Limits, ScAddress, WriteXdr}, 
-    vec, Address, Env, FromVal, IntoVal, String, Symbol,
+    vec, Address, Env, FromVal, IntoVal, String, Symbol, TryFromVal, Val,
 };
 use stellar_strkey;
 
-use crate::utils::quantize_to_kg;
+use crate::contract::SinkReceipt;
+use crate::storage_types::{QuantizationUnit, RoundingPolicy};
+use crate::utils::quantize;
 use crate::{errors::SinkError, tests::fixtures::Setup};
 
 #[derive(Clone)]
@@ -22,7 +24,7 @@ pub struct SinkTestData<'a> {
     pub memo_text: &'static str,
 }
 
-pub fn sink_carbon_with_auth(setup: &Setup, test_data: &SinkTestData) -> Result<(), SinkError> {
+pub fn sink_carbon_with_auth(setup: &Setup, test_data: &SinkTestData) -> Result<SinkReceipt, SinkError> {
     let env = &setup.env;
     let carbon_sac = &setup.carbon_sac;
     let contract_id = &setup.contract_id;
@@ -35,7 +37,8 @@ pub fn sink_carbon_with_auth(setup: &Setup, test_data: &SinkTestData) -> Result<
     let project_id = Symbol::new(env, test_data.project_id);
     let memo_text = String::from_str(env, test_data.memo_text);
     // we need to authorize the quantized amount for the burn call
-    let quantized_amount = (quantize_to_kg(amount) as i128).into_val(env);
+    let quantized_amount = quantize(amount, QuantizationUnit::Kilogram, RoundingPolicy::Truncate).unwrap();
+    let quantized_amount = (quantized_amount as i128).into_val(env);
     match client
         .mock_auths(&[MockAuth {
             address: funder,
@@ -57,13 +60,30 @@ pub fn sink_carbon_with_auth(setup: &Setup, test_data: &SinkTestData) -> Result<
         .try_sink_carbon(
             &funder, &recipient, &amount, &project_id, &memo_text,
         ) {
-            Ok(Ok(())) => Ok(()),
+            Ok(Ok(receipt)) => Ok(receipt),
             Err(Ok(sink_err)) => Err(sink_err),
             Ok(Err(conversion_err)) => panic!("ConversionError: {:?}", conversion_err),
             Err(Err(invoke_err)) => panic!("InvokeError: {:?}", invoke_err),
         }
 }
 
+/// Returns the data of the last event if it is an admin event for `fn_name`.
+pub fn last_admin_event<T>(env: &Env, fn_name: &str) -> T
+where
+    T: TryFromVal<Env, Val>,
+{
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            String::from_str(env, "sink_carbon"),
+            String::from_str(env, "admin"),
+            String::from_str(env, fn_name),
+        ).into_val(env)
+    );
+    T::try_from_val(env, &data).unwrap_or_else(|_| panic!("unexpected event data"))
+}
+
 pub fn deploy_native_sac(env: &Env) -> Address {
     let xdr_bytes = Asset::Native.to_xdr(Limits::none()).unwrap();
     let asset_slice = xdr_bytes.as_slice();
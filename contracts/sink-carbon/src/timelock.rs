@@ -0,0 +1,156 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use crate::errors::SinkError;
+use crate::fees::MAX_FEE_BPS;
+use crate::roles::{require_role, Role};
+use crate::storage_types::{extend_persistent_ttl, DataKey, DAY_IN_LEDGERS};
+
+/// The maximum timelock delay. Scheduled operations are kept in persistent storage,
+/// whose TTL is extended far beyond this delay.
+pub(crate) const MAX_TIMELOCK_DELAY: u32 = 30 * DAY_IN_LEDGERS;
+
+/// A sensitive admin operation. While the timelock delay is non-zero, these operations
+/// must be scheduled with `schedule_op` and can only be executed with `execute_op` once the delay has elapsed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AdminOp {
+    /// `set_contract_successor` with the address of the successor contract.
+    SetContractSuccessor(Address),
+    /// `reset_admin`.
+    ResetAdmin,
+    /// `set_minimum_sink_amount` with the new minimum sink amount.
+    SetMinimumSinkAmount(i64),
+    /// `set_fee_bps` with the new protocol fee in basis points.
+    SetFeeBps(u32),
+    /// `set_timelock_delay` with the new delay in ledgers.
+    SetTimelockDelay(u32),
+    /// `upgrade` with the hash of the uploaded WASM of the new contract version.
+    Upgrade(BytesN<32>),
+    /// `set_treasury` with the address of the new treasury.
+    SetTreasury(Address),
+}
+
+impl AdminOp {
+    /// The name of the contract function that performs this operation without a timelock.
+    pub fn fn_name(&self) -> &'static str {
+        match self {
+            AdminOp::SetContractSuccessor(_) => "set_contract_successor",
+            AdminOp::ResetAdmin => "reset_admin",
+            AdminOp::SetMinimumSinkAmount(_) => "set_minimum_sink_amount",
+            AdminOp::SetFeeBps(_) => "set_fee_bps",
+            AdminOp::SetTimelockDelay(_) => "set_timelock_delay",
+            AdminOp::Upgrade(_) => "upgrade",
+            AdminOp::SetTreasury(_) => "set_treasury",
+        }
+    }
+
+    /// The role that may schedule, execute and cancel this operation besides the admin,
+    /// or `None` if it is reserved to the admin.
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            AdminOp::SetContractSuccessor(_) | AdminOp::ResetAdmin => Some(Role::UpgradeManager),
+            AdminOp::SetMinimumSinkAmount(_) | AdminOp::SetFeeBps(_) => Some(Role::ConfigManager),
+            AdminOp::SetTimelockDelay(_) | AdminOp::Upgrade(_) | AdminOp::SetTreasury(_) => None,
+        }
+    }
+
+    /// Validates the arguments of this operation, so that an invalid operation cannot be scheduled.
+    pub fn validate(&self) -> Result<(), SinkError> {
+        match self {
+            AdminOp::SetMinimumSinkAmount(amount) if *amount < 0 => Err(SinkError::NegativeAmount),
+            AdminOp::SetFeeBps(fee_bps) if *fee_bps > MAX_FEE_BPS => Err(SinkError::FeeTooHigh),
+            AdminOp::SetTimelockDelay(delay) if *delay > MAX_TIMELOCK_DELAY => Err(SinkError::TimelockDelayTooLong),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An admin operation that has been scheduled with `schedule_op`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ScheduledOp {
+    pub id: u64,
+    pub op: AdminOp,
+    /// The first ledger in which the operation can be executed.
+    pub ready_ledger: u32,
+}
+
+pub fn read_timelock_delay(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::TimelockDelay).unwrap_or(0)
+}
+
+pub fn write_timelock_delay(env: &Env, delay: u32) {
+    env.storage().instance().set(&DataKey::TimelockDelay, &delay);
+}
+
+/// Fails with `OperationTimelocked` if sensitive admin operations must be scheduled.
+pub fn require_no_timelock(env: &Env) -> Result<(), SinkError> {
+    if read_timelock_delay(env) > 0 {
+        return Err(SinkError::OperationTimelocked);
+    }
+    Ok(())
+}
+
+/// Requires the authorization of `caller`, which must be the admin or hold the role of `op`.
+pub fn require_op_role(env: &Env, caller: &Address, op: &AdminOp) -> Result<(), SinkError> {
+    match op.required_role() {
+        Some(role) => require_role(env, caller, role),
+        None => {
+            caller.require_auth();
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            if *caller == admin {
+                Ok(())
+            } else {
+                Err(SinkError::Unauthorized)
+            }
+        }
+    }
+}
+
+/// Stores `op` as a new scheduled operation that is ready after the current timelock delay.
+/// IDs start at 1, so an ID of 0 never refers to a scheduled operation.
+pub fn schedule(env: &Env, op: AdminOp) -> ScheduledOp {
+    let id = read_scheduled_op_count(env) + 1;
+    env.storage().instance().set(&DataKey::ScheduledOpCount, &id);
+
+    let scheduled = ScheduledOp {
+        id,
+        op,
+        ready_ledger: env.ledger().sequence().saturating_add(read_timelock_delay(env)),
+    };
+    let key = DataKey::ScheduledOp(id);
+    env.storage().persistent().set(&key, &scheduled);
+    extend_persistent_ttl(env, &key);
+    scheduled
+}
+
+pub fn read_scheduled_op(env: &Env, id: u64) -> Option<ScheduledOp> {
+    let key = DataKey::ScheduledOp(id);
+    let scheduled: Option<ScheduledOp> = env.storage().persistent().get(&key);
+    if scheduled.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    scheduled
+}
+
+/// Reads the number of operations that have ever been scheduled, which is also the ID of the latest one.
+pub fn read_scheduled_op_count(env: &Env) -> u64 {
+    env.storage().instance().get(&DataKey::ScheduledOpCount).unwrap_or(0)
+}
+
+/// Extends the TTL of the pending operations among up to `limit` scheduled operations,
+/// starting with the operation with ID `cursor + 1`.
+pub fn extend_scheduled_ops_ttl(env: &Env, cursor: u32, limit: u32) {
+    let end = cursor.saturating_add(limit) as u64;
+    for id in (cursor as u64 + 1)..=end.min(read_scheduled_op_count(env)) {
+        // executed and cancelled operations have been removed
+        let key = DataKey::ScheduledOp(id);
+        if env.storage().persistent().has(&key) {
+            extend_persistent_ttl(env, &key);
+        }
+    }
+}
+
+pub fn remove_scheduled_op(env: &Env, id: u64) {
+    env.storage().persistent().remove(&DataKey::ScheduledOp(id));
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::Env;
+
+use crate::projects::{extend_registry_ttl, read_project_count};
+use crate::retirements::{extend_retirements_ttl, get_retirement_count};
+use crate::roles::{extend_role_ttl, Role};
+use crate::screening::{read_list, read_list_count, ScreeningList};
+use crate::storage_types::{extend_operators_ttl, read_set_count, DataKey};
+use crate::timelock::{extend_scheduled_ops_ttl, read_scheduled_op_count};
+
+/// The maximum number of items that `extend_ttl` extends in a single call. An item, e.g. a project
+/// with its inventory and total, or a retirement record with the totals of its recipient and funder,
+/// has up to three entries, which must all fit in the footprint of the transaction.
+pub(crate) const MAX_TTL_PAGE_SIZE: u32 = 20;
+
+/// A group of persistent entries that `extend_storage_ttl` extends, in the order of `TTL_SECTIONS`.
+#[derive(Clone, Copy)]
+enum TtlSection {
+    Registry,
+    RoleMembers(Role),
+    Operators,
+    List(ScreeningList),
+    ScheduledOps,
+    Retirements,
+}
+
+const TTL_SECTIONS: [TtlSection; 9] = [
+    TtlSection::Registry,
+    TtlSection::RoleMembers(Role::Pauser),
+    TtlSection::RoleMembers(Role::ConfigManager),
+    TtlSection::RoleMembers(Role::UpgradeManager),
+    TtlSection::Operators,
+    TtlSection::List(ScreeningList::Denylist),
+    TtlSection::List(ScreeningList::Allowlist),
+    TtlSection::ScheduledOps,
+    TtlSection::Retirements,
+];
+
+impl TtlSection {
+    /// The number of items in this section.
+    fn len(self, env: &Env) -> u32 {
+        match self {
+            TtlSection::Registry => read_project_count(env),
+            TtlSection::RoleMembers(role) => read_set_count(env, &DataKey::RoleCount(role)),
+            TtlSection::Operators => read_set_count(env, &DataKey::OperatorCount),
+            TtlSection::List(list) => read_list_count(env, list),
+            TtlSection::ScheduledOps => read_scheduled_op_count(env) as u32,
+            TtlSection::Retirements => get_retirement_count(env) as u32,
+        }
+    }
+
+    /// Extends the TTL of up to `limit` items of this section, starting at position `cursor`.
+    fn extend(self, env: &Env, cursor: u32, limit: u32) {
+        match self {
+            TtlSection::Registry => extend_registry_ttl(env, cursor, limit),
+            TtlSection::RoleMembers(role) => extend_role_ttl(env, role, cursor, limit),
+            TtlSection::Operators => extend_operators_ttl(env, cursor, limit),
+            TtlSection::List(list) => {
+                read_list(env, list, cursor, limit);
+            }
+            TtlSection::ScheduledOps => extend_scheduled_ops_ttl(env, cursor, limit),
+            TtlSection::Retirements => extend_retirements_ttl(env, cursor, limit),
+        }
+    }
+}
+
+/// Extends the TTL of a page of the persistent entries that can go unused for longer than their TTL:
+/// the project registry, the role members, the operator approvals, the screening lists, the scheduled
+/// operations and the retirement records with the totals of their recipients and funders. The items of
+/// all sections are numbered consecutively in that order, and the page consists of up to `limit` items,
+/// starting with item number `cursor`.
+/// Returns the cursor of the next page, or `None` if this was the last page.
+pub fn extend_storage_ttl(env: &Env, cursor: u32, limit: u32) -> Option<u32> {
+    let end = cursor.saturating_add(limit.min(MAX_TTL_PAGE_SIZE));
+    let mut section_start: u32 = 0;
+    for section in TTL_SECTIONS {
+        let section_end = section_start.saturating_add(section.len(env));
+        if cursor < section_end && section_start < end {
+            let first = cursor.max(section_start);
+            section.extend(env, first - section_start, end.min(section_end) - first);
+        }
+        section_start = section_end;
+    }
+    if end < section_start {
+        Some(end)
+    } else {
+        None
+    }
+}
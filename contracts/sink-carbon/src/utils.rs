@@ -1,6 +1,17 @@
+use crate::errors::SinkError;
+use crate::storage_types::{QuantizationUnit, RoundingPolicy};
 
-pub fn quantize_to_kg(amount: i64) -> i64 {
-    const KG: i64 = 10_000;
-    let kg_amount = amount / KG;
-    kg_amount * KG
+/// Quantizes `amount` to a whole multiple of `unit`, according to the `rounding` policy.
+pub fn quantize(amount: i64, unit: QuantizationUnit, rounding: RoundingPolicy) -> Result<i64, SinkError> {
+    let unit = unit as i64;
+    let remainder = amount % unit;
+    let truncated = amount - remainder;
+    match rounding {
+        RoundingPolicy::Truncate => Ok(truncated),
+        RoundingPolicy::Strict if remainder != 0 => Err(SinkError::AmountNotAligned),
+        RoundingPolicy::Strict => Ok(amount),
+        // round halves up, unless that would overflow
+        RoundingPolicy::Nearest if remainder * 2 >= unit => Ok(truncated.checked_add(unit).unwrap_or(truncated)),
+        RoundingPolicy::Nearest => Ok(truncated),
+    }
 }